@@ -54,3 +54,10 @@ pub(crate) fn build(
 		Builder::Foreground => self::default::build(config, chapters),
 	}
 }
+
+/// Write the angular project without building it
+#[cfg(debug_assertions)]
+pub(crate) fn write_project(config: &Config, chapters: Vec<ChapterWithCodeBlocks>) -> Result<()> {
+	self::default::write(config, chapters)?;
+	Ok(())
+}
@@ -55,7 +55,10 @@ pub(super) fn write_angular_workspace(
 	Ok(())
 }
 
-pub(super) fn build(config: &Config, chapters: Vec<ChapterWithCodeBlocks>) -> Result<()> {
+/// Write the angular project for the given chapters
+///
+/// This returns the paths of the chapters.
+pub(super) fn write(config: &Config, chapters: Vec<ChapterWithCodeBlocks>) -> Result<Vec<PathBuf>> {
 	let root = &config.angular_root_folder;
 	let mut writer = Writer::new(false);
 
@@ -85,7 +88,13 @@ pub(super) fn build(config: &Config, chapters: Vec<ChapterWithCodeBlocks>) -> Re
 
 	writer.write_main(config, root)?;
 
-	ng_build(root)?;
+	Ok(chapter_paths)
+}
+
+pub(super) fn build(config: &Config, chapters: Vec<ChapterWithCodeBlocks>) -> Result<()> {
+	let chapter_paths = write(config, chapters)?;
+
+	ng_build(&config.angular_root_folder)?;
 
 	if config.optimize {
 		replace_load_angular_script_path(config, chapter_paths)?;
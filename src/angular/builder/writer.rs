@@ -1,8 +1,13 @@
-use std::{fs, path::Path};
+use std::{env, fs, iter, path::Path};
 
-use serde_json::json;
+use log::warn;
+use serde_json::{json, Map, Value};
 
-use crate::{codeblock::CodeBlock, Config, Context, Result};
+use crate::{
+	codeblock::CodeBlock,
+	utils::{normalize_path, strip_json_comments, to_js_path},
+	Config, Context, Result,
+};
 
 pub(super) struct Writer {
 	changed_only: bool,
@@ -67,19 +72,81 @@ impl Writer {
 		);
 
 		for (code_block_index, code_block) in code_blocks.into_iter().enumerate() {
-			self.write(
-				absolute_project_folder.join(format!("codeblock_{code_block_index}.ts")),
-				&code_block.code_to_run,
-			)
-			.context("failed to write code block")?;
+			let module = if let Some(file_name) = &code_block.file_name {
+				let code_block_folder =
+					absolute_project_folder.join(format!("codeblock_{code_block_index}"));
 
-			main_script.push(format!(
-				"\
-					import {{{} as CodeBlock_{code_block_index}}} from './codeblock_{code_block_index}.js';\n\
-					applications.push(bootstrapApplication(CodeBlock_{code_block_index}, {{providers: makeProviders(CodeBlock_{code_block_index})}}));\n\
-				",
-				&code_block.class_name
-			));
+				let files = code_block
+					.files
+					.iter()
+					.map(|file| (&file.name, &file.code))
+					.chain(iter::once((file_name, &code_block.code_to_run)));
+
+				for (name, code) in files {
+					let path = code_block_folder.join(name);
+
+					if let Some(parent) = path.parent() {
+						fs::create_dir_all(parent).context("failed to create code block folder")?;
+					}
+
+					self.write(path, code)
+						.context("failed to write code block")?;
+				}
+
+				format!("./codeblock_{code_block_index}/{}", to_js_path(file_name))
+			} else {
+				self.write(
+					absolute_project_folder.join(format!("codeblock_{code_block_index}.ts")),
+					&code_block.code_to_run,
+				)
+				.context("failed to write code block")?;
+
+				format!("./codeblock_{code_block_index}.js")
+			};
+
+			for (component_index, component) in code_block.components.iter().enumerate() {
+				let alias = format!("CodeBlock_{code_block_index}_{component_index}");
+
+				let inputs = code_block
+					.inputs
+					.iter()
+					.filter_map(|(name, value)| {
+						let (_, config) =
+							component.inputs.iter().find(|(input, _)| input == name)?;
+
+						// Date inputs expect a `Date` rather than the ISO 8601 string
+						let value = if config.is_date() && !value.is_null() {
+							format!("new Date({value})")
+						} else {
+							value.to_string()
+						};
+
+						Some(format!(
+							"component.setInput({}, {value});",
+							serde_json::to_string(name).unwrap()
+						))
+					})
+					.collect::<Vec<_>>();
+
+				// Only set the inputs the component declares, setting an
+				// unknown input throws
+				let set_inputs = if inputs.is_empty() {
+					String::new()
+				} else {
+					format!(
+						".then(app => {{ const component = app.components[0]; zone.run(() => {{ {} }}); return app; }})",
+						inputs.join(" ")
+					)
+				};
+
+				main_script.push(format!(
+					"\
+						import {{{} as {alias}}} from '{module}';\n\
+						applications.push(bootstrapApplication({alias}, {{providers: makeProviders({alias})}}){set_inputs});\n\
+					",
+					&component.class_name
+				));
+			}
 		}
 
 		let script_basename = project_folder.clone();
@@ -130,7 +197,7 @@ impl Writer {
 	}
 
 	pub(super) fn write_tsconfig(&self, config: &Config) -> Result<()> {
-		let tsconfig = if let Some(tsconfig) = &config.tsconfig {
+		let mut tsconfig = if let Some(tsconfig) = &config.tsconfig {
 			json!({"extends": tsconfig.to_string_lossy()})
 		} else {
 			json!({
@@ -148,6 +215,33 @@ impl Writer {
 			})
 		};
 
+		if !config.aliases.is_empty() {
+			let current_dir = env::current_dir()?;
+			// Setting paths replaces the paths of the extended tsconfig, so keep
+			// these paths next to the aliases
+			let mut paths = config
+				.tsconfig
+				.as_deref()
+				.map(read_tsconfig_paths)
+				.unwrap_or_default();
+
+			for (alias, folder) in &config.aliases {
+				// Use absolute paths, relative paths are resolved relative to the
+				// baseUrl if any is set in the extended tsconfig.
+				let folder = current_dir.join(config.book_source_folder.join(folder));
+
+				paths.insert(
+					format!("{}/*", alias.trim_end_matches('/')),
+					json!([format!(
+						"{}/*",
+						folder.to_string_lossy().trim_end_matches('/')
+					)]),
+				);
+			}
+
+			tsconfig["compilerOptions"]["paths"] = Value::Object(paths);
+		}
+
 		self.write(
 			config.angular_root_folder.join("tsconfig.json"),
 			&serde_json::to_string(&tsconfig)?,
@@ -157,3 +251,52 @@ impl Writer {
 		Ok(())
 	}
 }
+
+/// Read the `compilerOptions.paths` of the given tsconfig
+///
+/// TypeScript resolves paths relative to the tsconfig containing them unless a
+/// `baseUrl` is set, so these paths are made absolute if the tsconfig doesn't
+/// set a `baseUrl`.
+fn read_tsconfig_paths(path: &Path) -> Map<String, Value> {
+	let Some(tsconfig) = fs::read_to_string(path)
+		.ok()
+		.and_then(|tsconfig| serde_json::from_str::<Value>(&strip_json_comments(&tsconfig)).ok())
+	else {
+		warn!(
+			"Failed to read the paths of {}, only the aliases are available as paths",
+			path.display()
+		);
+		return Map::new();
+	};
+
+	let compiler_options = &tsconfig["compilerOptions"];
+	let Some(paths) = compiler_options["paths"].as_object() else {
+		return Map::new();
+	};
+
+	if compiler_options.get("baseUrl").is_some() {
+		return paths.clone();
+	}
+
+	let folder = path.parent().unwrap_or(Path::new(""));
+
+	paths
+		.iter()
+		.map(|(alias, targets)| {
+			let targets = match targets {
+				Value::Array(targets) => targets
+					.iter()
+					.map(|target| match target.as_str() {
+						Some(target) => {
+							json!(normalize_path(&folder.join(target)).to_string_lossy())
+						}
+						None => target.clone(),
+					})
+					.collect(),
+				_ => targets.clone(),
+			};
+
+			(alias.clone(), targets)
+		})
+		.collect()
+}
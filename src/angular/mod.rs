@@ -2,6 +2,8 @@ mod builder;
 mod workspace;
 
 pub(crate) use builder::build;
+#[cfg(debug_assertions)]
+pub(crate) use builder::write_project;
 
 use workspace::AngularWorkspace;
 
@@ -0,0 +1,135 @@
+use std::collections::BTreeMap;
+
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+
+/// A comment at the end of a line marking a callout, e.g. `// <1>`,
+/// `/* <2> */` or `<!-- <3> -->`, optionally followed by an explanation
+static CALLOUT_MARKER: Lazy<Regex> = Lazy::new(|| {
+	Regex::new(
+		r"(?m)(?<comment>//|/\*|<!--)[ \t]*<(?<number>\d{1,3})>[ \t]*(?<text>.*?)[ \t]*(?<end>\*/|-->)?[ \t]*$",
+	)
+	.unwrap()
+});
+
+/// A line explaining a callout in the markdown following an example, e.g.
+/// `<1> Inject the service`
+static CALLOUT_EXPLANATION: Lazy<Regex> =
+	Lazy::new(|| Regex::new(r"^[ \t]*<(?<number>\d{1,3})>[ \t]+(?<text>.*?)\s*$").unwrap());
+
+/// Get the badge shown in place of the callout with the given number
+fn badge(number: u32) -> String {
+	match number {
+		// ① through ⑳
+		1..=20 => char::from_u32(0x2460 + number - 1).unwrap().to_string(),
+		_ => format!("({number})"),
+	}
+}
+
+/// Replace all callout markers in the given code with numbered badges
+///
+/// The number of every callout is added to `callouts`, together with the text
+/// following the marker in the comment, if any.
+pub(crate) fn replace_callouts(code: &str, callouts: &mut BTreeMap<u32, String>) -> String {
+	CALLOUT_MARKER
+		.replace_all(code, |captures: &Captures| {
+			let Ok(number) = captures["number"].parse::<u32>() else {
+				return captures[0].to_owned();
+			};
+
+			let text = &captures["text"];
+			let explanation = callouts.entry(number).or_default();
+			if explanation.is_empty() {
+				text.clone_into(explanation);
+			}
+
+			match captures.name("end") {
+				Some(end) => format!(
+					"{} {} {}",
+					&captures["comment"],
+					badge(number),
+					end.as_str()
+				),
+				None => format!("{} {}", &captures["comment"], badge(number)),
+			}
+		})
+		.into_owned()
+}
+
+/// Find the list of callout explanations directly following an example that
+/// ends at the given offset
+///
+/// This returns the explanations and the offset the list ends at, which equals
+/// the given offset if the example isn't followed by explanations.
+pub(crate) fn find_callout_explanations(
+	markdown: &str,
+	start: usize,
+) -> (BTreeMap<u32, String>, usize) {
+	let mut explanations = BTreeMap::new();
+	let mut end = start;
+	let mut offset = start;
+
+	for (i, line) in markdown[start..].split_inclusive('\n').enumerate() {
+		let content = line.trim_end_matches(['\r', '\n']);
+
+		if let Some(captures) = CALLOUT_EXPLANATION.captures(content) {
+			if let Ok(number) = captures["number"].parse() {
+				explanations.insert(number, captures["text"].to_owned());
+				end = offset + content.len();
+			}
+		} else if !content.trim().is_empty() || (i > 0 && !explanations.is_empty()) {
+			// Only empty lines are allowed between the example and the list,
+			// and the list ends at the first line that isn't an explanation
+			break;
+		}
+
+		offset += line.len();
+	}
+
+	(explanations, end)
+}
+
+#[cfg(test)]
+mod test {
+	use std::collections::BTreeMap;
+
+	use super::{find_callout_explanations, replace_callouts};
+
+	#[test]
+	fn test_replace_callouts() {
+		let mut callouts = BTreeMap::new();
+
+		assert_eq!(
+			"inject(Lorem); // ①\nipsum(); /* ② */\n<p>dolor</p> <!-- ③ -->\n",
+			replace_callouts(
+				"inject(Lorem); // <1> Inject lorem\nipsum(); /* <2> */\n<p>dolor</p> <!-- <3> -->\n",
+				&mut callouts
+			)
+		);
+		assert_eq!(
+			BTreeMap::from([
+				(1, "Inject lorem".to_owned()),
+				(2, String::new()),
+				(3, String::new()),
+			]),
+			callouts
+		);
+	}
+
+	#[test]
+	fn test_find_callout_explanations() {
+		let markdown = "{{#angular ./lorem.ts}}\n\n<1> Lorem\n<2> Ipsum\n\nDolor\n";
+
+		assert_eq!(
+			(
+				BTreeMap::from([(1, "Lorem".to_owned()), (2, "Ipsum".to_owned())]),
+				44
+			),
+			find_callout_explanations(markdown, 23)
+		);
+		assert_eq!(
+			(BTreeMap::new(), 46),
+			find_callout_explanations(markdown, 46)
+		);
+	}
+}
@@ -0,0 +1,84 @@
+use std::ops::Range;
+
+use crate::{bail, Result};
+
+/// A replacement of a range of source code
+pub(super) struct Edit {
+	range: Range<usize>,
+	replacement: String,
+}
+
+impl Edit {
+	pub(super) fn new(range: Range<usize>, replacement: String) -> Self {
+		Self { range, replacement }
+	}
+
+	pub(super) fn insert(at: usize, insertion: String) -> Self {
+		Self::new(at..at, insertion)
+	}
+
+	pub(super) fn range(&self) -> &Range<usize> {
+		&self.range
+	}
+
+	/// Create a copy of this edit for a slice of the source starting at the
+	/// given offset
+	pub(super) fn shifted(&self, offset: usize) -> Self {
+		Self::new(
+			self.range.start - offset..self.range.end - offset,
+			self.replacement.clone(),
+		)
+	}
+}
+
+/// Apply the given edits to the source
+///
+/// This fails if any of the edits overlap.
+pub(super) fn apply_edits<'a, I: IntoIterator<Item = &'a Edit>>(
+	source: &str,
+	edits: I,
+) -> Result<String> {
+	let mut edits: Vec<&Edit> = edits.into_iter().collect();
+	edits.sort_by_key(|edit| (edit.range.start, edit.range.end));
+
+	let mut result = String::with_capacity(source.len());
+	let mut last = 0;
+
+	for edit in edits {
+		if edit.range.start < last {
+			bail!(
+				"Failed to edit the code, the edit at {}..{} overlaps a previous edit",
+				edit.range.start,
+				edit.range.end
+			);
+		}
+
+		result.push_str(&source[last..edit.range.start]);
+		result.push_str(&edit.replacement);
+		last = edit.range.end;
+	}
+
+	result.push_str(&source[last..]);
+
+	Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+	use super::{apply_edits, Edit};
+
+	#[test]
+	fn test_apply_edits() {
+		let edits = [
+			Edit::new(6..11, "dolor".to_owned()),
+			Edit::insert(0, "> ".to_owned()),
+		];
+		assert_eq!("> lorem dolor", apply_edits("lorem ipsum", &edits).unwrap());
+
+		let overlapping = [
+			Edit::new(0..8, String::new()),
+			Edit::new(6..11, "dolor".to_owned()),
+		];
+		assert!(apply_edits("lorem ipsum", &overlapping).is_err());
+	}
+}
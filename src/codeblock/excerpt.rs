@@ -0,0 +1,183 @@
+use std::ops::RangeInclusive;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::{bail, Result};
+
+static ANCHOR_START: Lazy<Regex> = Lazy::new(|| Regex::new(r"ANCHOR:\s*(?<name>[\w_-]+)").unwrap());
+static ANCHOR_END: Lazy<Regex> =
+	Lazy::new(|| Regex::new(r"ANCHOR_END:\s*(?<name>[\w_-]+)").unwrap());
+
+/// Part of a file to show
+#[derive(Debug, PartialEq, Eq)]
+enum Excerpt<'a> {
+	/// A range of lines, starting from 1
+	Lines(RangeInclusive<usize>),
+	/// All lines between `ANCHOR: <name>` and `ANCHOR_END: <name>`
+	Anchor(&'a str),
+}
+
+impl<'a> Excerpt<'a> {
+	/// Parse an excerpt using the same syntax mdbook uses in `{{#include}}`
+	fn parse(value: &'a str) -> Result<Self> {
+		static LINES: Lazy<Regex> =
+			Lazy::new(|| Regex::new(r"^(?<start>\d*)(?<range>:(?<end>\d*))?$").unwrap());
+
+		let Some(captures) = LINES.captures(value) else {
+			return Ok(Self::Anchor(value));
+		};
+
+		let start = match captures.name("start").map(|m| m.as_str()) {
+			Some("") | None => 1,
+			Some(start) => start.parse()?,
+		};
+
+		let end = if captures.name("range").is_some() {
+			match captures.name("end").map(|m| m.as_str()) {
+				Some("") | None => usize::MAX,
+				Some(end) => end.parse()?,
+			}
+		} else {
+			start
+		};
+
+		if start == 0 || end < start {
+			bail!("Invalid line range {value}, lines start at 1");
+		}
+
+		Ok(Self::Lines(start..=end))
+	}
+}
+
+fn is_anchor(regex: &Regex, line: &str, name: &str) -> bool {
+	regex
+		.captures(line)
+		.is_some_and(|captures| &captures["name"] == name)
+}
+
+fn is_anchor_line(line: &str) -> bool {
+	ANCHOR_START.is_match(line) || ANCHOR_END.is_match(line)
+}
+
+/// Take the part of the given code described by the excerpt
+///
+/// The excerpt is either a line range (`10`, `10:25`, `10:` or `:25`) or the
+/// name of an anchor. Lines marking anchors are left out of the result, and
+/// indentation common to all lines is removed.
+pub(super) fn take_excerpt(code: &str, excerpt: &str) -> Result<String> {
+	let lines: Vec<&str> = match Excerpt::parse(excerpt)? {
+		Excerpt::Lines(range) => code
+			.lines()
+			.enumerate()
+			.filter(|(index, _)| range.contains(&(index + 1)))
+			.map(|(_, line)| line)
+			.collect(),
+		Excerpt::Anchor(name) => {
+			let mut lines = code.lines();
+
+			if !lines.any(|line| is_anchor(&ANCHOR_START, line, name)) {
+				bail!("Failed to find anchor {name}");
+			}
+
+			lines
+				.take_while(|line| !is_anchor(&ANCHOR_END, line, name))
+				.collect()
+		}
+	};
+
+	Ok(dedent(
+		&lines
+			.into_iter()
+			.filter(|line| !is_anchor_line(line))
+			.collect::<Vec<_>>(),
+	))
+}
+
+/// Remove all lines marking anchors
+pub(super) fn strip_anchors(code: &str) -> String {
+	let mut result = code
+		.lines()
+		.filter(|line| !is_anchor_line(line))
+		.collect::<Vec<_>>()
+		.join("\n");
+
+	if code.ends_with('\n') {
+		result.push('\n');
+	}
+
+	result
+}
+
+/// Remove the indentation all non-empty lines have in common
+pub(super) fn dedent(lines: &[&str]) -> String {
+	let indentation = lines
+		.iter()
+		.filter(|line| !line.trim().is_empty())
+		.map(|line| line.len() - line.trim_start().len())
+		.min()
+		.unwrap_or(0);
+
+	let mut result = String::new();
+
+	for line in lines {
+		result.push_str(line.get(indentation..).unwrap_or_default());
+		result.push('\n');
+	}
+
+	result
+}
+
+#[cfg(test)]
+mod test {
+	use super::{strip_anchors, take_excerpt, Excerpt};
+
+	const CODE: &str = "\
+class Lorem {
+	// ANCHOR: ipsum
+	ipsum() {
+		// ANCHOR: dolor
+		return 'dolor';
+		// ANCHOR_END: dolor
+	}
+	// ANCHOR_END: ipsum
+}
+";
+
+	#[test]
+	fn test_parse_excerpt() {
+		assert_eq!(Excerpt::Lines(10..=10), Excerpt::parse("10").unwrap());
+		assert_eq!(Excerpt::Lines(10..=25), Excerpt::parse("10:25").unwrap());
+		assert_eq!(
+			Excerpt::Lines(10..=usize::MAX),
+			Excerpt::parse("10:").unwrap()
+		);
+		assert_eq!(Excerpt::Lines(1..=25), Excerpt::parse(":25").unwrap());
+		assert_eq!(Excerpt::Anchor("lorem"), Excerpt::parse("lorem").unwrap());
+		assert!(Excerpt::parse("25:10").is_err());
+	}
+
+	#[test]
+	fn test_take_lines() {
+		assert_eq!("ipsum() {\n", take_excerpt(CODE, "3:4").unwrap());
+		assert_eq!("\treturn 'dolor';\n}\n", take_excerpt(CODE, "5:7").unwrap());
+	}
+
+	#[test]
+	fn test_take_anchor() {
+		assert_eq!(
+			"ipsum() {\n\treturn 'dolor';\n}\n",
+			take_excerpt(CODE, "ipsum").unwrap()
+		);
+		assert_eq!("return 'dolor';\n", take_excerpt(CODE, "dolor").unwrap());
+		assert!(take_excerpt(CODE, "sit").is_err());
+	}
+
+	#[test]
+	fn test_strip_anchors() {
+		assert_eq!(
+			"class Lorem {\n\tipsum() {\n\t\treturn 'dolor';\n\t}\n}\n",
+			strip_anchors(CODE)
+		);
+	}
+}
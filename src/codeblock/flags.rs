@@ -1,3 +1,5 @@
+use serde_json::Value;
+
 #[derive(PartialEq, Eq, Debug)]
 pub(super) enum CodeBlockFlags {
 	/// Do not show the source code
@@ -14,31 +16,242 @@ pub(super) enum CodeBlockFlags {
 
 	/// Do not insert the Angular root element into the page
 	NoInsert,
+
+	/// Show the entire file
+	Full,
+	/// Show only the component class
+	ClassOnly,
+
+	/// Show inline templates and styles in separate code blocks
+	SplitTemplate,
+
+	/// Bootstrap this code block in a group of code blocks
+	Entry,
+
+	/// An attribute with a value, e.g. `file=lorem.ts`
+	///
+	/// Known attributes are
+	/// - `file`: name of the file this code block represents, used to group
+	///   multiple code blocks into a single example
+	/// - `class`: name of the component class to bootstrap
+	/// - `summary`: text to show on a collapsed code block
+	/// - `id`: identifier of the example, used as anchor and to refer to the
+	///   example via `{{#angular-ref id}}`
+	/// - `caption`: caption shown above the example
+	/// - `template`: name of the template to render the example with
+	///
+	/// Other attributes are passed to the template as is.
+	Attribute(String, String),
+	/// Initial value of an input, e.g. `input.size=3`
+	Input(String, Value),
 }
 
-fn to_flag(value: &str) -> Option<CodeBlockFlags> {
-	match value {
+impl CodeBlockFlags {
+	/// Name of the flag as passed to templates, if it isn't an attribute or
+	/// input
+	pub(super) fn name(&self) -> Option<&'static str> {
+		match self {
+			CodeBlockFlags::Hide => Some("hide"),
+			CodeBlockFlags::Collapsed => Some("collapsed"),
+			CodeBlockFlags::Uncollapsed => Some("uncollapsed"),
+			CodeBlockFlags::Playground => Some("playground"),
+			CodeBlockFlags::NoPlayground => Some("no_playground"),
+			CodeBlockFlags::NoInsert => Some("no_insert"),
+			CodeBlockFlags::Full => Some("full"),
+			CodeBlockFlags::ClassOnly => Some("class_only"),
+			CodeBlockFlags::SplitTemplate => Some("split_template"),
+			CodeBlockFlags::Entry => Some("entry"),
+			CodeBlockFlags::Attribute(..) | CodeBlockFlags::Input(..) => None,
+		}
+	}
+}
+
+/// A single flag, with quotes removed
+struct Token {
+	text: String,
+	/// Whether (part of) the flag was quoted
+	quoted: bool,
+}
+
+fn to_flag(token: &Token) -> Option<CodeBlockFlags> {
+	if let Some((key, value)) = token.text.split_once('=') {
+		if let Some(input) = key.strip_prefix("input.") {
+			// Quoted values are strings, anything else is parsed as JSON
+			let value = if token.quoted {
+				Value::String(value.to_owned())
+			} else {
+				serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_owned()))
+			};
+
+			return Some(CodeBlockFlags::Input(input.to_owned(), value));
+		}
+
+		return Some(CodeBlockFlags::Attribute(key.to_owned(), value.to_owned()));
+	}
+
+	match token.text.as_str() {
 		"hide" => Some(CodeBlockFlags::Hide),
 		"playground" => Some(CodeBlockFlags::Playground),
 		"noplayground" | "no-playground" => Some(CodeBlockFlags::NoPlayground),
 		"uncollapsed" | "no-collapse" => Some(CodeBlockFlags::Uncollapsed),
 		"collapsed" | "collapse" => Some(CodeBlockFlags::Collapsed),
 		"no-insert" => Some(CodeBlockFlags::NoInsert),
+		"full" => Some(CodeBlockFlags::Full),
+		"class-only" => Some(CodeBlockFlags::ClassOnly),
+		"split-template" => Some(CodeBlockFlags::SplitTemplate),
+		"entry" => Some(CodeBlockFlags::Entry),
 		_ => None,
 	}
 }
 
 fn is_flag_separator(c: char) -> bool {
-	c == ',' || c == ' '
+	c == ',' || c.is_whitespace()
+}
+
+fn is_quote(c: char) -> bool {
+	c == '"' || c == '\''
+}
+
+/// Split the given string into separate flags
+///
+/// Quotes (either single or double) can be used to include separators in a
+/// flag, e.g. `summary="Show the code"`. Inside quotes, a backslash escapes
+/// the next character.
+///
+/// Separators inside of brackets or braces don't split the flag either, to
+/// support JSON arrays and objects, e.g. `input.items=["lorem", "ipsum"]`.
+/// Quotes inside of brackets or braces are kept.
+fn tokenize(string: &str) -> Vec<Token> {
+	let mut tokens = Vec::new();
+	let mut current = String::new();
+	let mut quoted = false;
+	let mut quote = None;
+	let mut depth = 0_usize;
+	let mut chars = string.chars();
+
+	while let Some(c) = chars.next() {
+		if depth > 0 {
+			current.push(c);
+
+			match quote {
+				Some(q) if c == q => quote = None,
+				Some(_) if c == '\\' => current.extend(chars.next()),
+				None if is_quote(c) => quote = Some(c),
+				None if c == '[' || c == '{' => depth += 1,
+				None if c == ']' || c == '}' => depth -= 1,
+				_ => {}
+			}
+
+			continue;
+		}
+
+		match quote {
+			Some(q) if c == q => quote = None,
+			Some(_) if c == '\\' => current.extend(chars.next()),
+			None if is_quote(c) => {
+				quote = Some(c);
+				quoted = true;
+			}
+			None if c == '[' || c == '{' => {
+				depth = 1;
+				current.push(c);
+			}
+			None if is_flag_separator(c) => {
+				if !current.is_empty() {
+					tokens.push(Token {
+						text: std::mem::take(&mut current),
+						quoted,
+					});
+				}
+
+				quoted = false;
+			}
+			_ => current.push(c),
+		}
+	}
+
+	if !current.is_empty() {
+		tokens.push(Token {
+			text: current,
+			quoted,
+		});
+	}
+
+	tokens
 }
 
 /// Extract flags from the given string
 ///
-/// The text should contain flags separated by space or comma.
+/// The text should contain flags separated by whitespace or comma.
 /// Unknown flags are ignored.
 pub(super) fn get_flags(string: &str) -> Vec<CodeBlockFlags> {
-	string
-		.split(is_flag_separator)
-		.filter_map(to_flag)
+	tokenize(string).iter().filter_map(to_flag).collect()
+}
+
+/// Extract the initial values of inputs from the given flags
+pub(super) fn get_inputs(flags: &[CodeBlockFlags]) -> Vec<(String, Value)> {
+	flags
+		.iter()
+		.filter_map(|flag| match flag {
+			CodeBlockFlags::Input(name, value) => Some((name.clone(), value.clone())),
+			_ => None,
+		})
 		.collect()
 }
+
+/// Extract the value of the attribute with the given name, if any
+pub(super) fn get_attribute<'a>(flags: &'a [CodeBlockFlags], name: &str) -> Option<&'a str> {
+	flags.iter().find_map(|flag| match flag {
+		CodeBlockFlags::Attribute(key, value) if key == name => Some(value.as_str()),
+		_ => None,
+	})
+}
+
+/// Extract the file name from the given flags, if any
+pub(super) fn get_file_name(flags: &[CodeBlockFlags]) -> Option<&str> {
+	get_attribute(flags, "file")
+}
+
+#[cfg(test)]
+mod test {
+	use serde_json::json;
+
+	use super::{get_flags, CodeBlockFlags};
+
+	#[test]
+	fn test_get_flags() {
+		assert_eq!(
+			vec![
+				CodeBlockFlags::Collapsed,
+				CodeBlockFlags::Attribute(
+					"summary".to_owned(),
+					"Show the \"full\", code".to_owned()
+				),
+				CodeBlockFlags::Attribute("class".to_owned(), "LoremComponent".to_owned()),
+				CodeBlockFlags::Attribute("id".to_owned(), "it's".to_owned()),
+				CodeBlockFlags::NoInsert,
+			],
+			get_flags(
+				r#"ts,angular,collapsed summary="Show the \"full\", code",class=LoremComponent id='it\'s'
+				no-insert"#
+			)
+		);
+	}
+
+	#[test]
+	fn test_input_flags() {
+		assert_eq!(
+			vec![
+				CodeBlockFlags::Input("variant".to_owned(), json!("danger")),
+				CodeBlockFlags::Input("size".to_owned(), json!(3)),
+				CodeBlockFlags::Input("label".to_owned(), json!("3")),
+				CodeBlockFlags::Input("text".to_owned(), json!("lorem")),
+				CodeBlockFlags::Input("items".to_owned(), json!(["a, b", "c"])),
+				CodeBlockFlags::Input("options".to_owned(), json!({"a": [1, 2]})),
+			],
+			get_flags(
+				r#"input.variant="danger" input.size=3 input.label='3' input.text=lorem input.items=["a, b", "c"],input.options={"a": [1, 2]}"#
+			)
+		);
+	}
+}
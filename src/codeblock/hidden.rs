@@ -0,0 +1,111 @@
+use std::ops::Range;
+
+use super::edit::Edit;
+
+/// A line hidden from the printed code, but still part of the code to run
+struct HiddenLine {
+	/// Range of the entire line, including the line ending
+	line: Range<usize>,
+	/// Position the prefix was removed from
+	prefix: usize,
+}
+
+/// Lines in a code block that should run but not be shown
+pub(super) struct HiddenLines {
+	/// The code with the prefix removed from all hidden lines
+	pub(super) code: String,
+	lines: Vec<HiddenLine>,
+}
+
+impl HiddenLines {
+	/// Find all lines starting with the given prefix
+	///
+	/// Whitespace is allowed in front of the prefix, and a single space following
+	/// the prefix is considered part of the prefix.
+	pub(super) fn find(code: &str, prefix: &str) -> Self {
+		let mut result = String::with_capacity(code.len());
+		let mut lines = Vec::new();
+
+		for line in code.split_inclusive('\n') {
+			let content = line.trim_start();
+			let indentation = &line[..line.len() - content.len()];
+
+			if let Some(content) = content.strip_prefix(prefix) {
+				let content = content.strip_prefix(' ').unwrap_or(content);
+				let start = result.len();
+
+				result.push_str(indentation);
+				result.push_str(content);
+
+				lines.push(HiddenLine {
+					line: start..result.len(),
+					prefix: start + indentation.len(),
+				});
+			} else {
+				result.push_str(line);
+			}
+		}
+
+		Self {
+			code: result,
+			lines,
+		}
+	}
+
+	pub(super) fn is_empty(&self) -> bool {
+		self.lines.is_empty()
+	}
+
+	/// Ranges of all hidden lines, including the line ending
+	pub(super) fn ranges(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+		self.lines.iter().map(|line| line.line.clone())
+	}
+
+	/// Whether the given edit changes a hidden line
+	pub(super) fn is_hidden(&self, edit: &Edit) -> bool {
+		self.lines
+			.iter()
+			.any(|line| line.line.contains(&edit.range().start))
+	}
+
+	/// Edits that remove all hidden lines
+	pub(super) fn removals(&self) -> impl Iterator<Item = Edit> + '_ {
+		self.lines
+			.iter()
+			.map(|line| Edit::new(line.line.clone(), String::new()))
+	}
+
+	/// Edits that add the given prefix back to all hidden lines
+	pub(super) fn prefixes<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = Edit> + 'a {
+		self.lines
+			.iter()
+			.map(move |line| Edit::insert(line.prefix, prefix.to_owned()))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use crate::codeblock::edit::apply_edits;
+
+	use super::HiddenLines;
+
+	const CODE: &str = "#! import {lorem} from 'ipsum';\n\nclass Dolor {\n\t#!sit = 'amet';\n}\n";
+
+	#[test]
+	fn test_hidden_lines() {
+		let hidden = HiddenLines::find(CODE, "#!");
+
+		assert_eq!(
+			"import {lorem} from 'ipsum';\n\nclass Dolor {\n\tsit = 'amet';\n}\n",
+			hidden.code
+		);
+		assert_eq!(
+			"\nclass Dolor {\n}\n",
+			apply_edits(&hidden.code, &hidden.removals().collect::<Vec<_>>()).unwrap()
+		);
+		assert_eq!(
+			"#!import {lorem} from 'ipsum';\n\nclass Dolor {\n\t#!sit = 'amet';\n}\n",
+			apply_edits(&hidden.code, &hidden.prefixes("#!").collect::<Vec<_>>()).unwrap()
+		);
+	}
+}
@@ -1,28 +1,73 @@
+mod callouts;
+mod edit;
+mod excerpt;
 mod flags;
+mod hidden;
 mod parser;
 pub(crate) mod playground;
+mod resolve;
 mod types;
 
-use std::path::Path;
+use std::{
+	collections::BTreeMap,
+	ffi::OsStr,
+	fs,
+	path::{self, Path},
+	rc::Rc,
+};
+
+use serde_json::Value;
 
-pub(crate) use types::{CodeBlock, PrintedCodeBlock};
+pub(crate) use callouts::{find_callout_explanations, replace_callouts};
+pub(crate) use resolve::PathResolver;
+pub(crate) use types::{
+	CodeBlock, CodeFile, Component, ExampleLabel, PrintedCodeBlock, PrintedFile,
+};
 
-use crate::{Config, Result};
+use crate::{bail, Config, Context, Result};
 
 use self::{
-	flags::get_flags,
-	parser::{parse_codeblock, ParsedCodeBlock},
+	edit::apply_edits,
+	excerpt::{strip_anchors, take_excerpt},
+	flags::{get_attribute, get_file_name, get_flags, get_inputs, CodeBlockFlags},
+	hidden::HiddenLines,
+	parser::{parse_codeblock, resolve_file_paths, ParseOptions, ParsedCodeBlock},
 };
 
+/// Where the code of a code block comes from
+pub(crate) enum CodeBlockSource<'a> {
+	/// An angular code block inside a chapter
+	Inline {
+		/// Resolver for relative paths, if the location of the chapter is known
+		resolver: Option<&'a PathResolver>,
+		/// Name of the file inside the example
+		file: &'a str,
+	},
+	/// A file referenced via `{{#angular}}` tag
+	File {
+		/// Path to the file
+		path: &'a Path,
+		/// Path to re-export the file from the working directory
+		reexport_path: &'a Path,
+		/// Part of the file to show, if any
+		excerpt: Option<&'a str>,
+	},
+}
+
 pub(crate) fn is_angular_codeblock(language: &str) -> bool {
 	language.contains("angular")
 }
 
+/// Whether the given angular code block is part of a group of code blocks
+pub(crate) fn is_grouped_codeblock(language: &str) -> bool {
+	get_file_name(&get_flags(language)).is_some()
+}
+
 pub(crate) fn to_codeblock<L: AsRef<str>, C: AsRef<str>>(
 	config: &Config,
 	index: usize,
 	class_name: Option<&str>,
-	reexport_path: Option<&Path>,
+	source: &CodeBlockSource,
 	language: L,
 	code: C,
 	code_to_print: &Option<C>,
@@ -30,56 +75,513 @@ pub(crate) fn to_codeblock<L: AsRef<str>, C: AsRef<str>>(
 	let code = code.as_ref();
 	let flags = get_flags(language.as_ref());
 
-	let hidden = flags.contains(&flags::CodeBlockFlags::Hide);
+	let class_names = get_class_names(class_name, &flags);
 
-	let insert = !flags.contains(&flags::CodeBlockFlags::NoInsert);
+	let hidden = flags.contains(&CodeBlockFlags::Hide);
 
-	let collapsed = if flags.contains(&flags::CodeBlockFlags::Collapsed) {
-		true
-	} else if flags.contains(&flags::CodeBlockFlags::Uncollapsed) {
-		false
-	} else {
-		config.collapsed
+	let insert = !flags.contains(&CodeBlockFlags::NoInsert);
+
+	let collapsed = is_collapsed(config, &flags);
+
+	let layout = match get_attribute(&flags, "layout") {
+		Some(layout) => layout.parse()?,
+		None => config.layout,
 	};
 
-	let allow_playground = if flags.contains(&flags::CodeBlockFlags::NoPlayground) {
-		false
-	} else if flags.contains(&flags::CodeBlockFlags::Playground) {
-		true
-	} else {
-		config.playgrounds
+	let code_to_print = match source {
+		CodeBlockSource::File {
+			excerpt: Some(excerpt),
+			..
+		} => Some(take_excerpt(code, excerpt)?),
+		CodeBlockSource::File { .. } => {
+			if flags.contains(&CodeBlockFlags::ClassOnly)
+				|| (class_names.is_some() && !flags.contains(&CodeBlockFlags::Full))
+			{
+				None
+			} else {
+				Some(code.to_owned())
+			}
+		}
+		CodeBlockSource::Inline { .. } => code_to_print.as_ref().map(|c| c.as_ref().to_owned()),
 	};
 
 	let ParsedCodeBlock {
 		code_to_print,
+		code_with_hidden_lines,
 		code_to_run,
-		playground,
-		class_name,
-		tag,
+		mut components,
+		resources,
+		listings,
 	} = parse_codeblock(
 		code,
-		code_to_print.as_ref().map(AsRef::as_ref),
-		allow_playground,
+		code_to_print.as_deref(),
 		index,
-		class_name,
-		reexport_path,
+		class_names.as_deref(),
+		source,
+		&ParseOptions {
+			allow_playground: allows_playground(config, &flags),
+			split_template: flags.contains(&CodeBlockFlags::SplitTemplate),
+			hidden_line_prefix: get_hidden_line_prefix(config),
+		},
 	)?;
 
+	let code_to_print = match source {
+		CodeBlockSource::File { .. } => Rc::new(strip_anchors(&code_to_print)),
+		CodeBlockSource::Inline { .. } => code_to_print,
+	};
+
+	let inputs = get_inputs(&flags);
+
+	check_inputs(&components, &inputs)?;
+	set_input_defaults(&mut components, &inputs);
+
 	let code_to_print = if hidden {
 		None
 	} else {
+		let (name, mut resources) = match source {
+			CodeBlockSource::File { path, .. } => (
+				path.file_name()
+					.map(|name| name.to_string_lossy().into_owned()),
+				read_resources(path, &resources)?,
+			),
+			CodeBlockSource::Inline { .. } => (None, Vec::new()),
+		};
+		resources.extend(listings);
+
 		Some(PrintedCodeBlock {
 			code: code_to_print,
+			code_with_hidden_lines: code_with_hidden_lines.filter(|_| config.hidden_lines_toggle),
 			collapsed,
+			name,
+			files: Vec::new(),
+			resources,
 		})
 	};
 
 	Ok(CodeBlock {
 		code_to_print,
 		code_to_run,
-		class_name,
+		components,
+		file_name: None,
+		files: Vec::new(),
 		insert,
-		tag,
-		playground,
+		layout,
+		inputs,
+		language: get_language(language.as_ref()).to_owned(),
+		flags: flags.iter().filter_map(CodeBlockFlags::name).collect(),
+		source_path: match source {
+			CodeBlockSource::File { path, .. } => Some(path.to_path_buf()),
+			CodeBlockSource::Inline { .. } => None,
+		},
+		attributes: get_attributes(&flags),
+		label: None,
 	})
 }
+
+/// Ensure every input set via an `input.<name>=` flag is declared by at least
+/// one of the given components, and that its value fits the range of number
+/// inputs
+fn check_inputs(components: &[Component], inputs: &[(String, Value)]) -> Result<()> {
+	for (name, value) in inputs {
+		let mut declared = false;
+
+		for (_, config) in components
+			.iter()
+			.flat_map(|component| &component.inputs)
+			.filter(|(input, _)| input == name)
+		{
+			config
+				.check_range(value)
+				.with_context(|| format!("Invalid value for flag input.{name}"))?;
+			declared = true;
+		}
+
+		if !declared {
+			let class_names = components
+				.iter()
+				.map(|component| component.class_name.as_str())
+				.collect::<Vec<_>>()
+				.join(", ");
+
+			bail!("Flag input.{name} sets an input that isn't declared by {class_names}");
+		}
+	}
+
+	Ok(())
+}
+
+/// Use the initial values of inputs in the playgrounds of the given components
+fn set_input_defaults(components: &mut [Component], inputs: &[(String, Value)]) {
+	for playground in components
+		.iter_mut()
+		.filter_map(|component| component.playground.as_mut())
+	{
+		for input in &mut playground.inputs {
+			if let Some((_, value)) = inputs.iter().find(|(name, _)| *name == input.name) {
+				input.config.set_default(value.clone());
+			}
+		}
+	}
+}
+
+/// Turn a group of code blocks, each with a `file=` flag, into a single
+/// [`CodeBlock`]
+///
+/// The code block with the `entry` flag is bootstrapped, or the first code
+/// block if none of the code blocks is marked as entry.
+pub(crate) fn to_codeblock_group<L: AsRef<str>, C: AsRef<str>>(
+	config: &Config,
+	index: usize,
+	resolver: Option<&PathResolver>,
+	code_blocks: &[(L, C)],
+) -> Result<CodeBlock> {
+	let code_blocks = code_blocks
+		.iter()
+		.map(|(language, code)| {
+			(
+				language.as_ref(),
+				get_flags(language.as_ref()),
+				code.as_ref(),
+			)
+		})
+		.collect::<Vec<_>>();
+
+	let mut names = Vec::with_capacity(code_blocks.len());
+	for (_, flags, _) in &code_blocks {
+		let Some(name) = get_file_name(flags) else {
+			bail!("Expected all code blocks in a group to have a file name");
+		};
+
+		if !is_valid_file_name(name) {
+			bail!(
+				"Invalid file name {name}, file names must be relative and stay inside the example"
+			);
+		}
+
+		if names.contains(&name) {
+			bail!("Duplicate file name {name} in group of code blocks");
+		}
+
+		names.push(name);
+	}
+
+	let entry_index = code_blocks
+		.iter()
+		.position(|(_, flags, _)| flags.contains(&CodeBlockFlags::Entry))
+		.unwrap_or(0);
+
+	let (entry_language, _, entry_code) = code_blocks[entry_index];
+
+	let resolver = resolver.map(|resolver| resolver.for_files(&names));
+
+	let mut code_block = to_codeblock(
+		config,
+		index,
+		None,
+		&CodeBlockSource::Inline {
+			resolver: resolver.as_ref(),
+			file: names[entry_index],
+		},
+		entry_language,
+		entry_code,
+		&Some(entry_code),
+	)?;
+
+	let mut files = Vec::with_capacity(code_blocks.len() - 1);
+	let mut printed_files = Vec::with_capacity(code_blocks.len());
+
+	for (i, ((language, flags, code), name)) in code_blocks.iter().zip(&names).enumerate() {
+		let code = if i == entry_index {
+			code_block
+				.code_to_print
+				.as_ref()
+				.map(|printed| (printed.code.clone(), printed.code_with_hidden_lines.clone()))
+		} else {
+			let (code_to_run, code, code_with_hidden_lines) = split_hidden_lines(config, code)?;
+
+			let code_to_run = match &resolver {
+				Some(resolver) if is_typescript_file(name) => {
+					resolve_file_paths(&code_to_run, resolver, name)?
+				}
+				_ => code_to_run,
+			};
+
+			files.push(CodeFile {
+				name: (*name).to_owned(),
+				code: code_to_run,
+			});
+
+			if flags.contains(&CodeBlockFlags::Hide) {
+				None
+			} else {
+				Some((
+					code,
+					code_with_hidden_lines.filter(|_| config.hidden_lines_toggle),
+				))
+			}
+		};
+
+		if let Some((code, code_with_hidden_lines)) = code {
+			printed_files.push(PrintedFile {
+				name: (*name).to_owned(),
+				language: get_language(language).to_owned(),
+				code,
+				code_with_hidden_lines,
+			});
+		}
+	}
+
+	if let Some(code_to_print) = &mut code_block.code_to_print {
+		code_to_print.files = printed_files;
+	} else if let Some(first) = printed_files.first() {
+		// A hidden entry only hides its own file, not the other files
+		code_block.code_to_print = Some(PrintedCodeBlock {
+			code: first.code.clone(),
+			code_with_hidden_lines: first.code_with_hidden_lines.clone(),
+			collapsed: is_collapsed(config, &code_blocks[entry_index].1),
+			name: None,
+			files: printed_files,
+			resources: Vec::new(),
+		});
+	}
+
+	code_block.file_name = Some(names[entry_index].to_owned());
+	code_block.files = files;
+
+	Ok(code_block)
+}
+
+/// Whether to show the code of a code block with the given flags collapsed
+fn is_collapsed(config: &Config, flags: &[CodeBlockFlags]) -> bool {
+	if flags.contains(&CodeBlockFlags::Collapsed) {
+		true
+	} else if flags.contains(&CodeBlockFlags::Uncollapsed) {
+		false
+	} else {
+		config.collapsed
+	}
+}
+
+/// Read the templates and stylesheets used by the components in the given file
+fn read_resources(path: &Path, urls: &[String]) -> Result<Vec<PrintedFile>> {
+	let folder = path.parent().unwrap_or(Path::new(""));
+
+	urls.iter()
+		.map(|url| {
+			let mut code = fs::read_to_string(folder.join(url)).with_context(|| {
+				format!(
+					"Failed to read {url} used by components in {}",
+					path.display()
+				)
+			})?;
+
+			code.truncate(code.trim_end().len());
+
+			let name = url.strip_prefix("./").unwrap_or(url);
+			let language = Path::new(name)
+				.extension()
+				.and_then(OsStr::to_str)
+				.unwrap_or("text");
+
+			Ok(PrintedFile {
+				name: name.to_owned(),
+				language: language.to_owned(),
+				code: Rc::new(code),
+				code_with_hidden_lines: None,
+			})
+		})
+		.collect()
+}
+
+/// Collect all `key=value` attributes in the given flags
+fn get_attributes(flags: &[CodeBlockFlags]) -> BTreeMap<String, String> {
+	flags
+		.iter()
+		.filter_map(|flag| match flag {
+			CodeBlockFlags::Attribute(key, value) => Some((key.clone(), value.clone())),
+			_ => None,
+		})
+		.collect()
+}
+
+/// Whether to show a playground, taking into account both flags and
+/// configuration
+fn allows_playground(config: &Config, flags: &[CodeBlockFlags]) -> bool {
+	if flags.contains(&CodeBlockFlags::NoPlayground) {
+		false
+	} else if flags.contains(&CodeBlockFlags::Playground) {
+		true
+	} else {
+		config.playgrounds
+	}
+}
+
+/// Get the names of the components to bootstrap, if only some of the exported
+/// components should be bootstrapped
+///
+/// Both `#A,B` in tags and `class=A class=B` or `class="A,B"` in code blocks
+/// select a subset of the exported components.
+fn get_class_names<'a>(
+	class_name: Option<&'a str>,
+	flags: &'a [CodeBlockFlags],
+) -> Option<Vec<&'a str>> {
+	let class_names = match class_name {
+		Some(class_name) => split_class_names(class_name),
+		None => flags
+			.iter()
+			.filter_map(|flag| match flag {
+				CodeBlockFlags::Attribute(key, value) if key == "class" => Some(value.as_str()),
+				_ => None,
+			})
+			.flat_map(split_class_names)
+			.collect(),
+	};
+
+	Some(class_names).filter(|class_names| !class_names.is_empty())
+}
+
+/// Split a comma separated list of class names
+fn split_class_names(class_names: &str) -> Vec<&str> {
+	class_names
+		.split(',')
+		.map(str::trim)
+		.filter(|name| !name.is_empty())
+		.collect()
+}
+
+/// Get the prefix of hidden lines, if hidden lines are enabled
+fn get_hidden_line_prefix(config: &Config) -> Option<&str> {
+	Some(config.hidden_line_prefix.as_str()).filter(|prefix| !prefix.is_empty())
+}
+
+/// The code to run, the code to print and the code to print including hidden
+/// lines of a file
+type SplitCode = (Rc<String>, Rc<String>, Option<Rc<String>>);
+
+/// Split the hidden lines from the code of a file
+///
+/// This returns the code to run, the code to print and, if the file contains
+/// hidden lines, the code to print including the hidden lines.
+fn split_hidden_lines(config: &Config, code: &str) -> Result<SplitCode> {
+	let Some((hidden_lines, prefix)) = get_hidden_line_prefix(config)
+		.map(|prefix| (HiddenLines::find(code, prefix), prefix))
+		.filter(|(hidden_lines, _)| !hidden_lines.is_empty())
+	else {
+		let code = Rc::new(code.to_owned());
+		return Ok((code.clone(), code, None));
+	};
+
+	let code_to_print = apply_edits(
+		&hidden_lines.code,
+		&hidden_lines.removals().collect::<Vec<_>>(),
+	)?;
+	let code_with_hidden_lines = apply_edits(
+		&hidden_lines.code,
+		&hidden_lines.prefixes(prefix).collect::<Vec<_>>(),
+	)?;
+
+	Ok((
+		Rc::new(hidden_lines.code),
+		Rc::new(code_to_print),
+		Some(Rc::new(code_with_hidden_lines)),
+	))
+}
+
+/// Get the language of a code block, i.e. the first flag of the code block
+fn get_language(language: &str) -> &str {
+	language.split([',', ' ']).next().unwrap_or_default()
+}
+
+fn is_typescript_file(name: &str) -> bool {
+	Path::new(name).extension().is_some_and(|ext| {
+		matches!(
+			ext.to_str(),
+			Some("ts" | "mts" | "cts" | "js" | "mjs" | "cjs")
+		)
+	})
+}
+
+fn is_valid_file_name(name: &str) -> bool {
+	!name.is_empty()
+		&& Path::new(name)
+			.components()
+			.all(|component| matches!(component, path::Component::Normal(_)))
+}
+
+#[cfg(test)]
+mod test {
+	use serde_json::{json, Value};
+
+	use super::{check_inputs, playground::PlaygroundInputConfig, Component};
+
+	fn component(class_name: &str, inputs: &[(&str, Value)]) -> Component {
+		Component {
+			class_name: class_name.to_owned(),
+			tag: String::new(),
+			inputs: inputs
+				.iter()
+				.map(|(name, config)| {
+					(
+						(*name).to_owned(),
+						serde_json::from_value::<PlaygroundInputConfig>(config.clone()).unwrap(),
+					)
+				})
+				.collect(),
+			playground: None,
+		}
+	}
+
+	#[test]
+	fn test_check_inputs() {
+		let components = [
+			component("FirstComponent", &[("text", json!({"type": "string"}))]),
+			component("SecondComponent", &[("label", json!({"type": "string"}))]),
+		];
+
+		assert!(check_inputs(&components, &[("text".to_owned(), json!("lorem"))]).is_ok());
+		assert!(check_inputs(&components, &[("label".to_owned(), json!("ipsum"))]).is_ok());
+		assert_eq!(
+			check_inputs(&components, &[("size".to_owned(), json!(3))])
+				.unwrap_err()
+				.to_string(),
+			"Flag input.size sets an input that isn't declared by FirstComponent, SecondComponent"
+		);
+	}
+
+	#[test]
+	fn test_check_input_ranges() {
+		let components = [component(
+			"RangeComponent",
+			&[(
+				"size",
+				json!({"type": "number", "min": 1, "max": 9, "step": 2}),
+			)],
+		)];
+
+		let check = |value: Value| {
+			check_inputs(&components, &[("size".to_owned(), value)])
+				.map_err(|error| format!("{error:#}"))
+		};
+
+		assert_eq!(Ok(()), check(json!(1)));
+		assert_eq!(Ok(()), check(json!(7)));
+		assert_eq!(
+			Err("Invalid value for flag input.size: 0 is less than the minimum of 1".to_owned()),
+			check(json!(0))
+		);
+		assert_eq!(
+			Err(
+				"Invalid value for flag input.size: 11 is greater than the maximum of 9".to_owned()
+			),
+			check(json!(11))
+		);
+		assert_eq!(
+			Err("Invalid value for flag input.size: 4 doesn't match the step of 2".to_owned()),
+			check(json!(4))
+		);
+		assert_eq!(
+			Err("Invalid value for flag input.size: Expected a number, got \"4\"".to_owned()),
+			check(json!("4"))
+		);
+	}
+}
@@ -1,4 +1,4 @@
-use std::{io, path::Path, rc::Rc};
+use std::{io, ops::Range, rc::Rc};
 
 use log::debug;
 use once_cell::sync::Lazy;
@@ -16,19 +16,58 @@ use swc_core::{
 	},
 };
 
-use crate::{utils::swc::get_decorator, Error, Result};
+use crate::{
+	utils::{
+		swc::{get_decorator, get_property},
+		to_js_path,
+	},
+	Context, Error, Result,
+};
 
-use super::playground::{parse_playground, Playground};
+use super::{
+	edit::{apply_edits, Edit},
+	excerpt::dedent,
+	hidden::HiddenLines,
+	playground::parse_playground,
+	resolve::PathResolver,
+	types::{Component, PrintedFile},
+	CodeBlockSource,
+};
 
-static TS_EXT: Lazy<Regex> = Lazy::new(|| Regex::new(r"\.([cm]?)ts(x?)$").unwrap());
 static START_OF_FILE: BytePos = BytePos(1);
 
+/// Replacement of inline templates and styles moved into separate listings
+const SPLIT_PLACEHOLDER: &str = "`...`";
+
+/// Options for parsing a code block
+pub(super) struct ParseOptions<'a> {
+	/// Whether to parse the playground of the components
+	pub(super) allow_playground: bool,
+	/// Whether to move inline templates and styles into separate listings
+	pub(super) split_template: bool,
+	/// Prefix of hidden lines, if hidden lines are enabled
+	pub(super) hidden_line_prefix: Option<&'a str>,
+}
+
 pub(super) struct ParsedCodeBlock {
 	pub(super) code_to_run: Rc<String>,
 	pub(super) code_to_print: Rc<String>,
-	pub(super) playground: Option<Playground>,
-	pub(super) class_name: String,
-	pub(super) tag: String,
+	pub(super) code_with_hidden_lines: Option<Rc<String>>,
+	pub(super) components: Vec<Component>,
+	/// The `templateUrl`, `styleUrl` and `styleUrls` of the components, as
+	/// written in the code
+	pub(super) resources: Vec<String>,
+	/// Inline templates and styles moved out of the printed code
+	pub(super) listings: Vec<PrintedFile>,
+}
+
+/// An inline template or style of a component
+struct Listing {
+	class_name: String,
+	kind: &'static str,
+	language: &'static str,
+	code: String,
+	code_with_hidden_lines: Option<String>,
 }
 
 struct CodeBlockVisitor {
@@ -37,27 +76,27 @@ struct CodeBlockVisitor {
 	source_file: SourceFile,
 	comments: SingleThreadedComments,
 	code_to_print: Option<String>,
+	class_ranges: Vec<Range<usize>>,
 	allow_playground: bool,
-	playground: Option<Playground>,
-	tag: Option<String>,
-	class_name: Option<String>,
+	split_template: bool,
+	/// Ranges of the hidden lines in the source
+	hidden_lines: Vec<Range<usize>>,
+	/// Prefix of the hidden lines, used for the listings including hidden lines
+	hidden_line_prefix: String,
+	class_names: Option<Vec<String>>,
+	components: Vec<Component>,
+	resources: Vec<String>,
+	listings: Vec<Listing>,
+	edits: Vec<Edit>,
+	/// Edits that only apply to the printed code
+	print_edits: Vec<Edit>,
 }
 
 impl CodeBlockVisitor {
 	fn get_selector(&mut self, decorator: &ast::ObjectLit, name: &str) -> Result<String> {
 		static INDENTATION: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s+").unwrap());
 
-		let selector = decorator
-			.props
-			.iter()
-			.filter_map(ast::PropOrSpread::as_prop)
-			.map(Box::as_ref)
-			.filter_map(ast::Prop::as_key_value)
-			.find(|kv| match &kv.key {
-				ast::PropName::Ident(ident) => ident.sym.eq("selector"),
-				ast::PropName::Str(str) => str.value.eq("selector"),
-				_ => false,
-			});
+		let selector = get_property(decorator, "selector");
 
 		if let Some(selector) = selector {
 			let selector = selector.value.as_lit().and_then(|lit| match lit {
@@ -74,7 +113,10 @@ impl CodeBlockVisitor {
 			};
 		}
 
-		let Some(generated_selector) = self.index.map(|i| format!("codeblock-{i}")) else {
+		let Some(generated_selector) = self.index.map(|i| match self.components.len() {
+			0 => format!("codeblock-{i}"),
+			n => format!("codeblock-{i}-{n}"),
+		}) else {
 			return Err(Error::msg(format!(
 				"Coudldn't find selector on class {name}"
 			)));
@@ -101,25 +143,132 @@ impl CodeBlockVisitor {
 
 		let insert = format!("selector: '{generated_selector}',\n{indentation}");
 
-		let (before, after) = self
-			.source
-			.split_at(span.lo.to_usize() - START_OF_FILE.to_usize());
+		self.edits.push(Edit::insert(
+			span.lo.to_usize() - START_OF_FILE.to_usize(),
+			insert,
+		));
 
-		let mut overwritten_source =
-			String::with_capacity(before.len() + insert.len() + after.len());
+		Ok(generated_selector)
+	}
 
-		overwritten_source.push_str(before);
-		overwritten_source.push_str(&insert);
-		overwritten_source.push_str(after);
+	/// Move the inline template and styles of a component into separate
+	/// listings, leaving a placeholder in the printed code
+	fn split_template(&mut self, name: &str, decorator: &ast::ObjectLit) {
+		let template = get_property(decorator, "template").map(|prop| &*prop.value);
+
+		let styles = match get_property(decorator, "styles").map(|prop| &*prop.value) {
+			Some(ast::Expr::Array(styles)) => styles
+				.elems
+				.iter()
+				.flatten()
+				.map(|elem| &*elem.expr)
+				.collect(),
+			Some(style) => vec![style],
+			None => Vec::new(),
+		};
 
-		self.code_to_print = Some(overwritten_source);
+		for (kind, language, literals) in [
+			("template", "html", template.into_iter().collect::<Vec<_>>()),
+			("styles", "css", styles),
+		] {
+			let mut code = Vec::new();
+			let mut code_with_hidden_lines = Vec::new();
 
-		Ok(generated_selector)
+			for literal in literals {
+				let Some(text) = get_static_string(literal) else {
+					continue;
+				};
+
+				let span = literal.span();
+				let range =
+					(span.lo - START_OF_FILE).to_usize()..(span.hi - START_OF_FILE).to_usize();
+
+				let (text, text_with_hidden_lines) = self.hide_lines_in_literal(&text, &range);
+
+				self.print_edits
+					.push(Edit::new(range, SPLIT_PLACEHOLDER.to_owned()));
+
+				code_with_hidden_lines.push(text_with_hidden_lines);
+				code.push(text);
+			}
+
+			if !code.is_empty() {
+				let code_with_hidden_lines =
+					code_with_hidden_lines.iter().any(Option::is_some).then(|| {
+						code_with_hidden_lines
+							.into_iter()
+							.zip(&code)
+							.map(|(with_hidden_lines, text)| {
+								trim_literal(with_hidden_lines.as_ref().unwrap_or(text))
+							})
+							.collect::<Vec<_>>()
+							.join("\n\n")
+					});
+
+				self.listings.push(Listing {
+					class_name: name.to_owned(),
+					kind,
+					language,
+					code: code
+						.iter()
+						.map(|text| trim_literal(text))
+						.collect::<Vec<_>>()
+						.join("\n\n"),
+					code_with_hidden_lines,
+				});
+			}
+		}
+	}
+
+	/// Remove the hidden lines from the text of the string literal at the given
+	/// range of the source
+	///
+	/// This returns the text without hidden lines and, if the literal contains
+	/// hidden lines, the text with the prefix in front of the hidden lines.
+	fn hide_lines_in_literal(&self, text: &str, range: &Range<usize>) -> (String, Option<String>) {
+		let hidden = self
+			.hidden_lines
+			.iter()
+			.filter(|line| range.start < line.start && line.start < range.end)
+			.map(|line| self.source[range.start..line.start].matches('\n').count())
+			.collect::<Vec<_>>();
+
+		// The lines of the text only match the lines of the source if the literal
+		// doesn't contain escaped line breaks
+		if hidden.is_empty()
+			|| text.split('\n').count() != self.source[range.clone()].split('\n').count()
+		{
+			return (text.to_owned(), None);
+		}
+
+		let lines = text.split_inclusive('\n').enumerate();
+
+		let without_hidden_lines = lines
+			.clone()
+			.filter(|(index, _)| !hidden.contains(index))
+			.map(|(_, line)| line)
+			.collect();
+		let with_hidden_lines = lines
+			.map(|(index, line)| {
+				if hidden.contains(&index) {
+					let content = line.trim_start();
+					let indentation = &line[..line.len() - content.len()];
+					format!("{indentation}{}{content}", self.hidden_line_prefix)
+				} else {
+					line.to_owned()
+				}
+			})
+			.collect();
+
+		(without_hidden_lines, Some(with_hidden_lines))
 	}
 
 	fn visit_exported_class(&mut self, name: &str, node: &ast::Class) -> Result<()> {
-		if let Some(expected_name) = &self.class_name {
-			if name.ne(expected_name) {
+		if let Some(expected_names) = &self.class_names {
+			if !expected_names
+				.iter()
+				.any(|expected_name| name == expected_name)
+			{
 				return Ok(());
 			}
 		}
@@ -141,23 +290,36 @@ impl CodeBlockVisitor {
 			return Ok(());
 		};
 
-		if self.tag.is_some() {
-			return Err(Error::msg(format!(
-				"File contains more than one exported component class: {} and {}",
-				self.tag.as_ref().unwrap(),
-				name
-			)));
-		}
-
 		let selector = self.get_selector(component, name)?;
 
-		self.tag = Some(selector);
-		self.class_name = Some(name.to_owned());
+		if self.split_template {
+			self.split_template(name, component);
+		}
 
-		if self.allow_playground {
-			self.playground = parse_playground(node, &self.comments)?;
+		let playground = parse_playground(node, &self.comments)?;
+		let inputs = playground
+			.iter()
+			.flat_map(|playground| &playground.inputs)
+			.map(|input| (input.name.clone(), input.config.clone()))
+			.collect();
+
+		for url in get_resource_urls(component) {
+			if !self
+				.resources
+				.iter()
+				.any(|resource| *resource == *url.value)
+			{
+				self.resources.push(url.value.to_string());
+			}
 		}
 
+		self.components.push(Component {
+			class_name: name.to_owned(),
+			tag: selector,
+			inputs,
+			playground: playground.filter(|_| self.allow_playground),
+		});
+
 		if self.code_to_print.is_none() {
 			let Span { hi, mut lo, .. } = node.span();
 
@@ -169,15 +331,67 @@ impl CodeBlockVisitor {
 				}
 			}
 
-			self.code_to_print = Some(
-				self.source[(lo - START_OF_FILE).to_usize()..(hi - START_OF_FILE).to_usize()]
-					.to_owned(),
-			);
+			self.class_ranges
+				.push((lo - START_OF_FILE).to_usize()..(hi - START_OF_FILE).to_usize());
 		}
 
 		Ok(())
 	}
 
+	/// Create the code to print, and the code to print including hidden lines
+	/// if the code contains hidden lines
+	fn print(
+		&self,
+		source: &CodeBlockSource,
+		hidden_lines: Option<&(HiddenLines, &str)>,
+	) -> Result<(Rc<String>, Option<Rc<String>>)> {
+		let print_edits = self
+			.edits
+			.iter()
+			.chain(&self.print_edits)
+			.collect::<Vec<_>>();
+
+		Ok(match (hidden_lines, &self.code_to_print) {
+			(Some((hidden_lines, prefix)), _) => {
+				let (code_to_print, code_with_hidden_lines) =
+					print_hidden_lines(&self.source, &print_edits, hidden_lines, prefix)?;
+				(code_to_print, Some(code_with_hidden_lines))
+			}
+			// Excerpts are shown as is
+			(None, Some(code_to_print))
+				if matches!(
+					source,
+					CodeBlockSource::File {
+						excerpt: Some(_),
+						..
+					}
+				) =>
+			{
+				(Rc::new(code_to_print.clone()), None)
+			}
+			(None, Some(_)) => (Rc::new(apply_edits(&self.source, print_edits)?), None),
+			(None, None) => {
+				let class_slices = self
+					.class_ranges
+					.iter()
+					.map(|range| {
+						let edits = print_edits
+							.iter()
+							.filter(|edit| {
+								range.start <= edit.range().start && edit.range().end <= range.end
+							})
+							.map(|edit| edit.shifted(range.start))
+							.collect::<Vec<_>>();
+
+						apply_edits(&self.source[range.clone()], &edits)
+					})
+					.collect::<Result<Vec<_>>>()?;
+
+				(Rc::new(class_slices.join("\n\n")), None)
+			}
+		})
+	}
+
 	fn visit_export_decl(&mut self, n: &ast::ExportDecl) -> Result<()> {
 		if let Some(n) = n.decl.as_class() {
 			self.visit_exported_class(&n.ident.sym, &n.class)?;
@@ -215,23 +429,161 @@ impl CodeBlockVisitor {
 	}
 }
 
-pub(super) fn parse_codeblock(
-	code: &str,
-	code_to_print: Option<&str>,
-	allow_playground: bool,
-	index: usize,
-	class_name: Option<&str>,
-	reexport_path: Option<&Path>,
-) -> Result<ParsedCodeBlock> {
-	let code = Rc::new(code.to_owned());
+/// Collect the edits required to resolve all relative paths in the given module
+///
+/// This takes into account imports and re-exports, and the `templateUrl`,
+/// `styleUrl` and `styleUrls` properties of components.
+fn resolve_paths(
+	source: &str,
+	module: &ast::Module,
+	resolver: &PathResolver,
+	file: &str,
+) -> Vec<Edit> {
+	let mut paths: Vec<&ast::Str> = Vec::new();
+
+	for item in &module.body {
+		let class = match item {
+			ast::ModuleItem::ModuleDecl(ast::ModuleDecl::Import(decl)) => {
+				paths.push(&decl.src);
+				continue;
+			}
+			ast::ModuleItem::ModuleDecl(ast::ModuleDecl::ExportAll(decl)) => {
+				paths.push(&decl.src);
+				continue;
+			}
+			ast::ModuleItem::ModuleDecl(ast::ModuleDecl::ExportNamed(decl)) => {
+				paths.extend(decl.src.as_deref());
+				continue;
+			}
+			ast::ModuleItem::ModuleDecl(ast::ModuleDecl::ExportDecl(ast::ExportDecl {
+				decl: ast::Decl::Class(decl),
+				..
+			}))
+			| ast::ModuleItem::Stmt(ast::Stmt::Decl(ast::Decl::Class(decl))) => &decl.class,
+			ast::ModuleItem::ModuleDecl(ast::ModuleDecl::ExportDefaultDecl(
+				ast::ExportDefaultDecl {
+					decl: ast::DefaultDecl::Class(decl),
+					..
+				},
+			)) => &decl.class,
+			_ => continue,
+		};
 
-	let handler = Handler::with_emitter_writer(Box::new(io::stderr()), None);
+		let Some(component) = get_decorator(&class.decorators, "Component")
+			.and_then(|decorator| decorator.expr.as_call())
+			.and_then(|call| call.args.first())
+			.and_then(|arg| arg.expr.as_object())
+		else {
+			continue;
+		};
 
-	let name: Rc<_> = FileName::Anon.into();
+		paths.extend(get_resource_urls(component));
+	}
 
-	let source_file = SourceFile::new_from(name.clone(), false, name, code.clone(), START_OF_FILE);
+	paths
+		.into_iter()
+		.filter_map(|str| {
+			let path = resolver.resolve(file, &str.value)?;
 
-	let comments = SingleThreadedComments::default();
+			let start = (str.span.lo - START_OF_FILE).to_usize();
+			let end = (str.span.hi - START_OF_FILE).to_usize();
+			let quote = &source[start..=start];
+
+			Some(Edit::new(start..end, format!("{quote}{path}{quote}")))
+		})
+		.collect()
+}
+
+/// Turn the inline templates and styles into files to print
+fn print_listings(listings: Vec<Listing>) -> Vec<PrintedFile> {
+	// Only mention the component if multiple components have listings
+	let name_by_class = listings
+		.iter()
+		.any(|listing| listing.class_name != listings[0].class_name);
+
+	listings
+		.into_iter()
+		.map(|listing| PrintedFile {
+			name: if name_by_class {
+				format!("{} {}", listing.class_name, listing.kind)
+			} else {
+				listing.kind.to_owned()
+			},
+			language: listing.language.to_owned(),
+			code: Rc::new(listing.code),
+			code_with_hidden_lines: listing.code_with_hidden_lines.map(Rc::new),
+		})
+		.collect()
+}
+
+/// Get the value of the given string literal or template literal without
+/// expressions
+fn get_static_string(expr: &ast::Expr) -> Option<String> {
+	match expr {
+		ast::Expr::Lit(ast::Lit::Str(str)) => Some(str.value.to_string()),
+		ast::Expr::Tpl(tpl) if tpl.exprs.is_empty() => tpl
+			.quasis
+			.first()
+			.map(|quasi| quasi.cooked.as_ref().unwrap_or(&quasi.raw).to_string()),
+		_ => None,
+	}
+}
+
+/// Remove leading and trailing empty lines and the shared indentation of an
+/// inline template or style
+fn trim_literal(text: &str) -> String {
+	let lines = text.lines().collect::<Vec<_>>();
+
+	let start = lines
+		.iter()
+		.position(|line| !line.trim().is_empty())
+		.unwrap_or(lines.len());
+	let end = lines
+		.iter()
+		.rposition(|line| !line.trim().is_empty())
+		.map_or(start, |index| index + 1);
+
+	let mut result = dedent(&lines[start..end]);
+	result.truncate(result.trim_end().len());
+
+	result
+}
+
+/// Get the `templateUrl`, `styleUrl` and `styleUrls` of the given component
+/// decorator, in that order
+fn get_resource_urls(component: &ast::ObjectLit) -> Vec<&ast::Str> {
+	let mut urls = Vec::new();
+
+	for name in ["templateUrl", "styleUrl"] {
+		if let Some(ast::Lit::Str(str)) =
+			get_property(component, name).and_then(|prop| prop.value.as_lit())
+		{
+			urls.push(str);
+		}
+	}
+
+	if let Some(style_urls) =
+		get_property(component, "styleUrls").and_then(|prop| prop.value.as_array())
+	{
+		urls.extend(style_urls.elems.iter().flatten().filter_map(
+			|elem| match elem.expr.as_lit() {
+				Some(ast::Lit::Str(str)) => Some(str),
+				_ => None,
+			},
+		));
+	}
+
+	urls
+}
+
+fn parse_program(
+	handler: &Handler,
+	code: Rc<String>,
+	comments: &SingleThreadedComments,
+) -> Result<(SourceFile, ast::Program)> {
+	let name: Rc<_> = FileName::Anon.into();
+
+	let source_file = SourceFile::new_from(name.clone(), false, name, code, START_OF_FILE);
 
 	let program = parser::parse_file_as_program(
 		&source_file,
@@ -243,71 +595,218 @@ pub(super) fn parse_codeblock(
 			disallow_ambiguous_jsx_like: false,
 		}),
 		EsVersion::latest(),
-		Some(&comments),
+		Some(comments),
 		&mut Vec::new(),
 	)
 	.map_err(|e| {
-		e.into_diagnostic(&handler).emit();
+		e.into_diagnostic(handler).emit();
 		Error::msg("Failed to parse code block")
 	})?;
 
+	Ok((source_file, program))
+}
+
+/// Resolve all relative paths in a file of an inline example
+pub(super) fn resolve_file_paths(
+	code: &str,
+	resolver: &PathResolver,
+	file: &str,
+) -> Result<Rc<String>> {
+	let code = Rc::new(code.to_owned());
+
+	let handler = Handler::with_emitter_writer(Box::new(io::stderr()), None);
+	let comments = SingleThreadedComments::default();
+
+	let (_, program) = parse_program(&handler, code.clone(), &comments)?;
+
+	let ast::Program::Module(module) = &program else {
+		return Ok(code);
+	};
+
+	let edits = resolve_paths(&code, module, resolver, file);
+
+	if edits.is_empty() {
+		Ok(code)
+	} else {
+		Ok(Rc::new(apply_edits(&code, &edits)?))
+	}
+}
+
+/// Create the code to print without and with hidden lines
+fn print_hidden_lines(
+	source: &str,
+	edits: &[&Edit],
+	hidden_lines: &HiddenLines,
+	prefix: &str,
+) -> Result<(Rc<String>, Rc<String>)> {
+	// Edits in hidden lines, e.g. a generated selector, would end up in a
+	// visible line, so leave those out of the printed code
+	let visible_edits: Vec<&Edit> = edits
+		.iter()
+		.copied()
+		.filter(|edit| !hidden_lines.is_hidden(edit))
+		.collect();
+
+	// Hidden lines inside templates and styles moved out of the printed code
+	// are hidden in their listings instead
+	let is_replaced = |edit: &Edit| {
+		visible_edits.iter().any(|replaced| {
+			!replaced.range().is_empty()
+				&& replaced.range().start <= edit.range().start
+				&& edit.range().end <= replaced.range().end
+		})
+	};
+
+	let removals: Vec<Edit> = hidden_lines
+		.removals()
+		.filter(|edit| !is_replaced(edit))
+		.collect();
+	let prefixes: Vec<Edit> = hidden_lines
+		.prefixes(prefix)
+		.filter(|edit| !is_replaced(edit))
+		.collect();
+
+	let context = "Hidden lines must lie entirely inside or outside of a template or style moved out via split-template";
+
+	Ok((
+		Rc::new(
+			apply_edits(source, visible_edits.iter().copied().chain(&removals)).context(context)?,
+		),
+		Rc::new(
+			apply_edits(source, visible_edits.iter().copied().chain(&prefixes)).context(context)?,
+		),
+	))
+}
+
+#[allow(clippy::too_many_lines)]
+pub(super) fn parse_codeblock(
+	code: &str,
+	code_to_print: Option<&str>,
+	index: usize,
+	class_names: Option<&[&str]>,
+	source: &CodeBlockSource,
+	options: &ParseOptions,
+) -> Result<ParsedCodeBlock> {
+	let hidden_lines = match (source, options.hidden_line_prefix) {
+		(CodeBlockSource::Inline { .. }, Some(prefix)) => Some(HiddenLines::find(code, prefix))
+			.filter(|hidden_lines| !hidden_lines.is_empty())
+			.map(|hidden_lines| (hidden_lines, prefix)),
+		_ => None,
+	};
+
+	let code = Rc::new(match &hidden_lines {
+		Some((hidden_lines, _)) => hidden_lines.code.clone(),
+		None => code.to_owned(),
+	});
+
+	let handler = Handler::with_emitter_writer(Box::new(io::stderr()), None);
+
+	let comments = SingleThreadedComments::default();
+
+	let (source_file, program) = parse_program(&handler, code.clone(), &comments)?;
+
 	let mut visitor = CodeBlockVisitor {
-		class_name: class_name.map(ToOwned::to_owned),
+		class_names: class_names
+			.map(|class_names| class_names.iter().map(|&name| name.to_owned()).collect()),
 		source: code,
 		source_file,
 		comments,
-		index: match reexport_path {
-			Some(_) => None,
-			None => Some(index),
+		index: match source {
+			CodeBlockSource::File { .. } => None,
+			CodeBlockSource::Inline { .. } => Some(index),
 		},
-		allow_playground,
-		playground: None,
+		allow_playground: options.allow_playground,
+		split_template: options.split_template,
+		hidden_lines: hidden_lines
+			.iter()
+			.flat_map(|(lines, _)| lines.ranges())
+			.collect(),
+		hidden_line_prefix: options.hidden_line_prefix.unwrap_or_default().to_owned(),
 		code_to_print: code_to_print.map(ToOwned::to_owned),
-		tag: None,
+		class_ranges: Vec::new(),
+		components: Vec::new(),
+		resources: Vec::new(),
+		listings: Vec::new(),
+		edits: Vec::new(),
+		print_edits: Vec::new(),
 	};
 
 	HANDLER.set(&handler, || visitor.visit_program(&program))?;
 
-	let Some(class_name) = visitor.class_name else {
-		return Err(match class_name {
-			Some(class_name) => Error::msg(format!("Failed to find class {class_name}")),
-			None => Error::msg("Failed to find component class"),
-		});
-	};
-
-	let Some(tag) = visitor.tag else {
-		return Err(Error::msg(format!(
-			"Failed to find selector on class {class_name}"
-		)));
-	};
+	if let Some(class_names) = class_names {
+		if let Some(missing) = class_names.iter().find(|&&name| {
+			!visitor
+				.components
+				.iter()
+				.any(|component| component.class_name == name)
+		}) {
+			return Err(Error::msg(format!(
+				"Failed to find component class {missing}"
+			)));
+		}
+	} else if visitor.components.is_empty() {
+		return Err(Error::msg("Failed to find component class"));
+	}
 
-	let code_to_print = visitor
-		.code_to_print
-		.map_or_else(|| visitor.source.clone(), Rc::new);
+	let (code_to_print, code_with_hidden_lines) = visitor.print(source, hidden_lines.as_ref())?;
 
-	let code_to_run = match reexport_path {
-		Some(reexport_path) => {
+	let code_to_run = match source {
+		CodeBlockSource::File { reexport_path, .. } => {
 			// TypeScript/JavaScript only support string paths, so... this should be
 			// fine otherwise things will not work, regardless of whether we can
 			// successfully print the path into the file.
 			let reexport_path = reexport_path.as_os_str().to_string_lossy();
 
-			let reexport_path = TS_EXT.replace_all(reexport_path.as_ref(), "$1js$2");
+			let reexport_path = to_js_path(reexport_path.as_ref());
+
+			let class_names = visitor
+				.components
+				.iter()
+				.map(|component| component.class_name.as_str())
+				.collect::<Vec<_>>()
+				.join(", ");
 
 			Rc::new(format!(
-				"export {{{class_name}}} from './{reexport_path}';\n"
+				"export {{{class_names}}} from './{reexport_path}';\n"
 			))
 		}
-		None => code_to_print.clone(),
+		CodeBlockSource::Inline {
+			resolver: Some(resolver),
+			file,
+		} => {
+			let path_edits = match &program {
+				ast::Program::Module(module) => {
+					resolve_paths(&visitor.source, module, resolver, file)
+				}
+				ast::Program::Script(_) => Vec::new(),
+			};
+
+			if path_edits.is_empty() && hidden_lines.is_none() && visitor.print_edits.is_empty() {
+				code_to_print.clone()
+			} else {
+				Rc::new(apply_edits(
+					&visitor.source,
+					visitor.edits.iter().chain(&path_edits),
+				)?)
+			}
+		}
+		CodeBlockSource::Inline { resolver: None, .. } => {
+			if hidden_lines.is_none() && visitor.print_edits.is_empty() {
+				code_to_print.clone()
+			} else {
+				Rc::new(apply_edits(&visitor.source, &visitor.edits)?)
+			}
+		}
 	};
 
-	let playground = visitor.playground;
+	let listings = print_listings(visitor.listings);
 
 	Ok(ParsedCodeBlock {
 		code_to_run,
 		code_to_print,
-		playground,
-		class_name,
-		tag,
+		code_with_hidden_lines,
+		components: visitor.components,
+		resources: visitor.resources,
+		listings,
 	})
 }
@@ -230,6 +230,86 @@ where
 	Some(PlaygroundInputConfig::number())
 }
 
+/// Evaluate the given array or object literal into a JSON value, if all of its
+/// elements and properties can be evaluated
+fn to_json(expr: &ast::Expr) -> Option<Value> {
+	match expr {
+		ast::Expr::Lit(ast::Lit::Null(_)) => Some(Value::Null),
+
+		ast::Expr::Array(ast::ArrayLit { elems, .. }) => elems
+			.iter()
+			.map(|elem| match elem {
+				Some(ast::ExprOrSpread { spread: None, expr }) => to_json(expr),
+				_ => None,
+			})
+			.collect::<Option<Vec<_>>>()
+			.map(Value::Array),
+
+		ast::Expr::Object(ast::ObjectLit { props, .. }) => props
+			.iter()
+			.map(|prop| {
+				let ast::PropOrSpread::Prop(prop) = prop else {
+					return None;
+				};
+				let ast::Prop::KeyValue(ast::KeyValueProp { key, value }) = prop.as_ref() else {
+					return None;
+				};
+
+				let key = match key {
+					ast::PropName::Ident(ast::IdentName { sym, .. }) => sym.to_string(),
+					ast::PropName::Str(ast::Str { value, .. }) => value.to_string(),
+					ast::PropName::Num(ast::Number { value, .. }) => value.to_string(),
+					_ => return None,
+				};
+
+				Some((key, to_json(value)?))
+			})
+			.collect::<Option<serde_json::Map<_, _>>>()
+			.map(Value::Object),
+
+		ast::Expr::Paren(ast::ParenExpr { expr, .. }) => to_json(expr),
+
+		_ => evaluate_expr(expr).and_then(|config| config.get_default().cloned()),
+	}
+}
+
+/// Whether the given type is an array or object type, e.g. `string[]`,
+/// `Array<string>` or `{lorem: string}`
+fn is_json_type(ts_type: &ast::TsType) -> bool {
+	match ts_type {
+		ast::TsType::TsArrayType(_) | ast::TsType::TsTupleType(_) | ast::TsType::TsTypeLit(_) => {
+			true
+		}
+
+		ast::TsType::TsTypeOperator(ast::TsTypeOperator {
+			op: ast::TsTypeOperatorOp::ReadOnly,
+			type_ann,
+			..
+		}) => is_json_type(type_ann),
+
+		ast::TsType::TsTypeRef(ast::TsTypeRef {
+			type_name: ast::TsEntityName::Ident(ident),
+			..
+		}) => matches!(
+			ident.sym.as_ref(),
+			"Array" | "ReadonlyArray" | "Record" | "Partial" | "Readonly"
+		),
+
+		_ => false,
+	}
+}
+
+fn is_nullish_type(ts_type: &ast::TsType) -> bool {
+	matches!(
+		ts_type,
+		ast::TsType::TsKeywordType(ast::TsKeywordType {
+			kind: ast::TsKeywordTypeKind::TsNullKeyword
+				| ast::TsKeywordTypeKind::TsUndefinedKeyword,
+			..
+		})
+	)
+}
+
 pub(super) fn ts_type_to_input_type<T: AsRef<ast::TsType>>(
 	ts_type: &T,
 ) -> Option<PlaygroundInputType> {
@@ -247,9 +327,19 @@ pub(super) fn ts_type_to_input_type<T: AsRef<ast::TsType>>(
 			..
 		}) => Some(PlaygroundInputType::Boolean),
 
+		ast::TsType::TsTypeRef(ast::TsTypeRef {
+			type_name: ast::TsEntityName::Ident(ident),
+			..
+		}) if ident.sym == "Date" => Some(PlaygroundInputType::Date),
+
+		ts_type if is_json_type(ts_type) => Some(PlaygroundInputType::Json),
+
 		ast::TsType::TsUnionOrIntersectionType(ast::TsUnionOrIntersectionType::TsUnionType(
 			ast::TsUnionType { types, .. },
 		)) => {
+			// Ignore `null` and `undefined`, e.g. in `Date | null`
+			let types: Vec<_> = types.iter().filter(|t| !is_nullish_type(t)).collect();
+
 			let string_types: Vec<_> = types
 				.iter()
 				.filter_map(|t| t.as_ts_lit_type())
@@ -259,6 +349,8 @@ pub(super) fn ts_type_to_input_type<T: AsRef<ast::TsType>>(
 
 			if string_types.len() == types.len() {
 				Some(PlaygroundInputType::Enum(string_types))
+			} else if let [ts_type] = types[..] {
+				ts_type_to_input_type(ts_type)
 			} else {
 				None
 			}
@@ -268,7 +360,11 @@ pub(super) fn ts_type_to_input_type<T: AsRef<ast::TsType>>(
 }
 
 pub(super) fn evaluate<T: AsRef<ast::Expr>>(expr: T) -> Option<PlaygroundInputConfig> {
-	match expr.as_ref() {
+	evaluate_expr(expr.as_ref())
+}
+
+fn evaluate_expr(expr: &ast::Expr) -> Option<PlaygroundInputConfig> {
+	match expr {
 		ast::Expr::Lit(ast::Lit::Bool(value)) => {
 			Some(PlaygroundInputConfig::from_default(value.value))
 		}
@@ -282,9 +378,32 @@ pub(super) fn evaluate<T: AsRef<ast::Expr>>(expr: T) -> Option<PlaygroundInputCo
 
 		ast::Expr::Tpl(_) => Some(PlaygroundInputConfig::string()),
 
+		ast::Expr::New(ast::NewExpr { callee, args, .. }) if callee.is_ident_ref_to("Date") => {
+			let default_ = match args.as_deref() {
+				Some([ast::ExprOrSpread { spread: None, expr }]) => {
+					expr.as_lit().and_then(|lit| match lit {
+						ast::Lit::Str(value) => Some(Value::String(value.value.to_string())),
+						_ => None,
+					})
+				}
+				_ => None,
+			};
+
+			Some(PlaygroundInputConfig::new(
+				default_,
+				PlaygroundInputType::Date,
+			))
+		}
+
+		ast::Expr::Array(_) | ast::Expr::Object(_) => Some(to_json(expr).map_or_else(
+			PlaygroundInputConfig::json,
+			PlaygroundInputConfig::from_default,
+		)),
+
 		ast::Expr::Unary(ast::UnaryExpr { op, arg, .. }) => apply_unary(*op, evaluate(arg)),
 
-		ast::Expr::TsNonNull(ast::TsNonNullExpr { expr, .. }) => evaluate(expr),
+		ast::Expr::Paren(ast::ParenExpr { expr, .. })
+		| ast::Expr::TsNonNull(ast::TsNonNullExpr { expr, .. }) => evaluate(expr),
 
 		ast::Expr::TsAs(ast::TsAsExpr { expr, type_ann, .. })
 		| ast::Expr::TsSatisfies(ast::TsSatisfiesExpr { expr, type_ann, .. })
@@ -9,7 +9,7 @@ use super::{
 	evaluate_expression::{evaluate, ts_type_to_input_type},
 	types::{
 		Playground, PlaygroundAction, PlaygroundInput, PlaygroundInputConfig,
-		PlaygroundInputConfigExt, PlaygroundInputType,
+		PlaygroundInputConfigExt, PlaygroundInputType, PlaygroundOutput,
 	},
 };
 
@@ -19,11 +19,16 @@ pub(crate) fn parse_playground<C: comments::Comments>(
 ) -> Result<Option<Playground>> {
 	let inputs = extract_inputs(node, comments)?;
 	let actions = extract_actions(node, comments);
+	let outputs = extract_outputs(node, comments);
 
-	if actions.is_empty() && inputs.is_empty() {
+	if actions.is_empty() && inputs.is_empty() && outputs.is_empty() {
 		Ok(None)
 	} else {
-		Ok(Some(Playground { inputs, actions }))
+		Ok(Some(Playground {
+			inputs,
+			actions,
+			outputs,
+		}))
 	}
 }
 
@@ -75,19 +80,7 @@ fn extract_inputs<C: comments::Comments>(
 			_ => continue,
 		};
 
-		let mut description: Option<String> = None;
-		let mut config: Option<PlaygroundInputConfig> = None;
-
-		if let Some(comment) = get_leading_comment(comments, member) {
-			let comment = clean_comment(&comment);
-
-			let mut parts = comment.splitn(2, "@input");
-			description = parts.next().map(ToString::to_string);
-
-			if let Some(default) = parts.next() {
-				config = Some(serde_json::from_str(default)?);
-			}
-		}
+		let (description, mut config) = parse_input_comment(comments, member)?;
 
 		if let Some(input_decorator) = get_decorator(decorators, "Input") {
 			let Some(name) = get_name_from_input_decorator(input_decorator)
@@ -111,12 +104,16 @@ fn extract_inputs<C: comments::Comments>(
 				name,
 				description,
 				config,
+				model: None,
 			});
 		} else if let Some(call) = value.as_ref().and_then(|value| value.as_call()) {
-			if call.callee.is_expr() && call.callee.as_expr().unwrap().is_ident_ref_to("input") {
-				let value = call.args.first().map(|v| &v.expr);
+			if let Some(signal) = get_signal_input(call) {
+				let is_required = signal == SignalInput::RequiredModel;
 
-				let Some(name) = get_name_from_input_signal(call)
+				// Required models don't have a default value, so their options come first
+				let value = call.args.first().filter(|_| !is_required).map(|v| &v.expr);
+
+				let Some(name) = get_alias_from_options(call, usize::from(!is_required))
 					.or_else(|| to_name(key).map(ToOwned::to_owned))
 				else {
 					continue;
@@ -141,6 +138,9 @@ fn extract_inputs<C: comments::Comments>(
 					name,
 					description,
 					config,
+					model: to_name(key)
+						.filter(|_| signal != SignalInput::Input)
+						.map(ToOwned::to_owned),
 				});
 			}
 		}
@@ -149,6 +149,53 @@ fn extract_inputs<C: comments::Comments>(
 	Ok(result)
 }
 
+/// Get the description and the explicit `@input` configuration from the
+/// comment above an input
+fn parse_input_comment<C: comments::Comments>(
+	comments: &C,
+	member: &ast::ClassMember,
+) -> Result<(Option<String>, Option<PlaygroundInputConfig>)> {
+	let Some(comment) = get_leading_comment(comments, member) else {
+		return Ok((None, None));
+	};
+
+	let comment = clean_comment(&comment);
+
+	let mut parts = comment.splitn(2, "@input");
+	let description = parts.next().map(ToString::to_string);
+
+	let config = match parts.next() {
+		Some(default) => Some(serde_json::from_str(default)?),
+		None => None,
+	};
+
+	Ok((description, config))
+}
+
+#[derive(PartialEq)]
+enum SignalInput {
+	/// `input()`
+	Input,
+	/// `model()`
+	Model,
+	/// `model.required()`
+	RequiredModel,
+}
+
+/// Get the kind of signal input created by the given call, if any
+fn get_signal_input(call: &ast::CallExpr) -> Option<SignalInput> {
+	match call.callee.as_expr()?.as_ref() {
+		callee if callee.is_ident_ref_to("input") => Some(SignalInput::Input),
+		callee if callee.is_ident_ref_to("model") => Some(SignalInput::Model),
+		ast::Expr::Member(ast::MemberExpr {
+			obj,
+			prop: ast::MemberProp::Ident(prop),
+			..
+		}) if obj.is_ident_ref_to("model") && prop.sym == "required" => Some(SignalInput::RequiredModel),
+		_ => None,
+	}
+}
+
 fn get_name_from_input_decorator(decorator: &ast::Decorator) -> Option<String> {
 	let arg = decorator
 		.expr
@@ -175,8 +222,10 @@ fn get_name_from_input_decorator(decorator: &ast::Decorator) -> Option<String> {
 	Some(alias.value.as_str().to_owned())
 }
 
-fn get_name_from_input_signal(call: &ast::CallExpr) -> Option<String> {
-	let opts = call.args.get(1)?;
+/// Get the `alias` from the options passed as argument at the given index to
+/// e.g. `input()` or `output()`
+fn get_alias_from_options(call: &ast::CallExpr, index: usize) -> Option<String> {
+	let opts = call.args.get(index)?;
 	let opts = opts.expr.as_object()?;
 
 	let alias = opts
@@ -184,7 +233,7 @@ fn get_name_from_input_signal(call: &ast::CallExpr) -> Option<String> {
 		.iter()
 		.filter_map(|prop| prop.as_prop())
 		.filter_map(|prop| prop.as_key_value())
-		.find(|prop| prop.key.is_str() && prop.key.as_str().unwrap().value.eq("alias"))?;
+		.find(|prop| to_name(&prop.key) == Some("alias"))?;
 
 	let ast::Lit::Str(alias) = alias.value.as_lit()? else {
 		return None;
@@ -231,6 +280,44 @@ fn extract_actions<C: comments::Comments>(
 		.collect()
 }
 
+fn extract_outputs<C: comments::Comments>(
+	node: &ast::Class,
+	comments: &C,
+) -> Vec<PlaygroundOutput> {
+	node.body
+		.iter()
+		.filter_map(ast::ClassMember::as_class_prop)
+		.filter_map(|prop| -> Option<PlaygroundOutput> {
+			let property = to_name(&prop.key)?.to_owned();
+
+			let name = if let Some(decorator) = get_decorator(&prop.decorators, "Output") {
+				get_name_from_input_decorator(decorator)
+			} else {
+				let call = prop.value.as_deref()?.as_call()?;
+				let callee = call.callee.as_expr()?;
+
+				if callee.is_ident_ref_to("output") {
+					get_alias_from_options(call, 0)
+				} else if callee.is_ident_ref_to("outputFromObservable") {
+					get_alias_from_options(call, 1)
+				} else {
+					return None;
+				}
+			};
+
+			let description = get_leading_comment(comments, prop)
+				.map(|comment| clean_comment(&comment))
+				.filter(|description| !description.is_empty());
+
+			Some(PlaygroundOutput {
+				name: name.unwrap_or_else(|| property.clone()),
+				property,
+				description,
+			})
+		})
+		.collect()
+}
+
 fn to_name(prop_name: &ast::PropName) -> Option<&str> {
 	match prop_name {
 		ast::PropName::Ident(ast::IdentName { sym, .. }) => Some(sym.as_ref()),
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-#[derive(Debug, PartialEq, Default, Serialize, Deserialize)]
+use crate::{bail, Result};
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub(crate) enum PlaygroundInputType {
 	#[default]
@@ -9,6 +11,21 @@ pub(crate) enum PlaygroundInputType {
 	Boolean,
 	Number,
 	Enum(Vec<String>),
+	/// Any JSON value, e.g. arrays and objects
+	Json,
+	/// A `Date`, with an ISO 8601 string as default value
+	Date,
+}
+
+/// How to show and edit string and date inputs
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum PlaygroundInputFormat {
+	Date,
+	Datetime,
+	Color,
+	Multiline,
+	Url,
 }
 
 impl PlaygroundInputType {
@@ -20,21 +37,41 @@ impl PlaygroundInputType {
 	// 	*self == PlaygroundInputType::Boolean
 	// }
 
-	// pub(crate) fn is_number(&self) -> bool {
-	// 	*self == PlaygroundInputType::Number
-	// }
-
 	pub(crate) fn is_enum(&self) -> bool {
 		matches!(self, PlaygroundInputType::Enum(_))
 	}
+
+	pub(crate) fn is_json(&self) -> bool {
+		*self == PlaygroundInputType::Json
+	}
+
+	pub(crate) fn is_date(&self) -> bool {
+		*self == PlaygroundInputType::Date
+	}
+
+	pub(crate) fn is_number(&self) -> bool {
+		*self == PlaygroundInputType::Number
+	}
 }
 
-#[derive(Debug, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
 pub(crate) struct PlaygroundInputConfig {
 	#[serde(rename = "type", default)]
 	type_: PlaygroundInputType,
 	#[serde(rename = "default")]
 	default_: Option<Value>,
+	/// Lower bound of number inputs
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	min: Option<f64>,
+	/// Upper bound of number inputs
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	max: Option<f64>,
+	/// Step of number inputs
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	step: Option<f64>,
+	/// Format of string and date inputs
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	format: Option<PlaygroundInputFormat>,
 }
 
 pub(super) trait PlaygroundInputConfigExt {
@@ -50,13 +87,26 @@ pub(super) trait PlaygroundInputConfigExt {
 impl PlaygroundInputConfigExt for PlaygroundInputConfig {
 	#[inline]
 	fn extend(self, config: PlaygroundInputConfig) -> PlaygroundInputConfig {
+		// A `@input` without type keeps the inferred enum, JSON or date type, and a
+		// `@input` with a range but without type is a number input
+		let type_ = if self.type_.is_string()
+			&& (config.type_.is_enum()
+				|| config.type_.is_json()
+				|| config.type_.is_date()
+				|| config.type_.is_number() && self.has_range())
+		{
+			config.type_
+		} else {
+			self.type_
+		};
+
 		PlaygroundInputConfig {
 			default_: self.default_.or(config.default_),
-			type_: if self.type_.is_string() && config.type_.is_enum() {
-				config.type_
-			} else {
-				self.type_
-			},
+			type_,
+			min: self.min.or(config.min),
+			max: self.max.or(config.max),
+			step: self.step.or(config.step),
+			format: self.format.or(config.format),
 		}
 	}
 
@@ -133,7 +183,11 @@ impl PlaygroundInputConfig {
 		default_: Option<Value>,
 		type_: PlaygroundInputType,
 	) -> PlaygroundInputConfig {
-		PlaygroundInputConfig { type_, default_ }
+		PlaygroundInputConfig {
+			type_,
+			default_,
+			..Default::default()
+		}
 	}
 
 	#[inline]
@@ -141,6 +195,7 @@ impl PlaygroundInputConfig {
 		PlaygroundInputConfig {
 			type_: PlaygroundInputType::Boolean,
 			default_: None,
+			..Default::default()
 		}
 	}
 
@@ -149,6 +204,16 @@ impl PlaygroundInputConfig {
 		PlaygroundInputConfig {
 			type_: PlaygroundInputType::Number,
 			default_: None,
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub(super) fn json() -> PlaygroundInputConfig {
+		PlaygroundInputConfig {
+			type_: PlaygroundInputType::Json,
+			default_: None,
+			..Default::default()
 		}
 	}
 
@@ -157,6 +222,7 @@ impl PlaygroundInputConfig {
 		PlaygroundInputConfig {
 			type_: PlaygroundInputType::String,
 			default_: None,
+			..Default::default()
 		}
 	}
 
@@ -165,6 +231,7 @@ impl PlaygroundInputConfig {
 		PlaygroundInputConfig {
 			type_,
 			default_: None,
+			..Default::default()
 		}
 	}
 
@@ -176,12 +243,60 @@ impl PlaygroundInputConfig {
 			type_: match &default_ {
 				Value::Bool(_) => PlaygroundInputType::Boolean,
 				Value::Number(_) => PlaygroundInputType::Number,
+				Value::Array(_) | Value::Object(_) => PlaygroundInputType::Json,
 				_ => PlaygroundInputType::String,
 			},
 			default_: Some(default_),
+			..Default::default()
 		}
 	}
 
+	#[inline]
+	fn has_range(&self) -> bool {
+		self.min.is_some() || self.max.is_some() || self.step.is_some()
+	}
+
+	#[inline]
+	pub(crate) fn set_default(&mut self, default_: Value) {
+		self.default_ = Some(default_);
+	}
+
+	/// Check that the given value of a number input lies within the range of
+	/// the input and matches its step
+	pub(crate) fn check_range(&self, value: &Value) -> Result<()> {
+		if !self.type_.is_number() || !self.has_range() {
+			return Ok(());
+		}
+
+		let Some(number) = value.as_f64() else {
+			bail!("Expected a number, got {value}");
+		};
+
+		if let Some(min) = self.min.filter(|min| number < *min) {
+			bail!("{number} is less than the minimum of {min}");
+		}
+
+		if let Some(max) = self.max.filter(|max| number > *max) {
+			bail!("{number} is greater than the maximum of {max}");
+		}
+
+		// Like HTML inputs, steps start at the minimum
+		if let Some(step) = self.step.filter(|step| *step > 0.0) {
+			let steps = (number - self.min.unwrap_or(0.0)) / step;
+
+			if (steps - steps.round()).abs() > 1e-9 {
+				bail!("{number} doesn't match the step of {step}");
+			}
+		}
+
+		Ok(())
+	}
+
+	#[inline]
+	pub(crate) fn is_date(&self) -> bool {
+		self.type_.is_date()
+	}
+
 	#[inline]
 	pub(super) fn get_type(self) -> PlaygroundInputType {
 		self.type_
@@ -192,6 +307,9 @@ pub(crate) struct PlaygroundInput {
 	pub(crate) name: String,
 	pub(crate) description: Option<String>,
 	pub(crate) config: PlaygroundInputConfig,
+	/// Name of the `model()` property, if the input is a model, used to show
+	/// values set by the component itself
+	pub(crate) model: Option<String>,
 }
 
 pub(crate) struct PlaygroundAction {
@@ -199,7 +317,16 @@ pub(crate) struct PlaygroundAction {
 	pub(crate) description: String,
 }
 
+pub(crate) struct PlaygroundOutput {
+	/// Name of the output as used in templates, i.e. the alias if it has one
+	pub(crate) name: String,
+	/// Name of the property on the component instance
+	pub(crate) property: String,
+	pub(crate) description: Option<String>,
+}
+
 pub(crate) struct Playground {
 	pub(crate) inputs: Vec<PlaygroundInput>,
 	pub(crate) actions: Vec<PlaygroundAction>,
+	pub(crate) outputs: Vec<PlaygroundOutput>,
 }
@@ -0,0 +1,144 @@
+use std::path::{Path, PathBuf};
+
+use pathdiff::diff_paths;
+
+use crate::utils::normalize_path;
+
+/// Extensions TypeScript tries when resolving an import without extension
+const EXTENSIONS: [&str; 4] = [".ts", ".js", "/index.ts", "/index.js"];
+
+/// Resolves relative paths in inline code blocks
+///
+/// Inline code blocks are written into the working directory, so relative
+/// imports or `templateUrl`s would resolve against the working directory rather
+/// than against the chapter containing the code block. This resolver rewrites
+/// these paths to point towards the folder of the chapter instead, except for
+/// paths pointing towards other files of the same example.
+pub(crate) struct PathResolver {
+	/// Folder containing the chapter
+	chapter_folder: PathBuf,
+	/// Folder the example is written into
+	///
+	/// This folder doesn't exist, only its depth inside the working directory
+	/// matters.
+	example_folder: PathBuf,
+	/// Names of all files that are part of the example
+	files: Vec<PathBuf>,
+}
+
+impl PathResolver {
+	/// Create a resolver for an example consisting of a single code block
+	pub(crate) fn new(angular_root_folder: &Path, chapter_folder: PathBuf) -> Self {
+		Self {
+			chapter_folder,
+			example_folder: angular_root_folder.join("code"),
+			files: Vec::new(),
+		}
+	}
+
+	/// Create a resolver for an example consisting of multiple files
+	pub(super) fn for_files<S: AsRef<str>>(&self, files: &[S]) -> Self {
+		Self {
+			chapter_folder: self.chapter_folder.clone(),
+			example_folder: self.example_folder.join("codeblock"),
+			files: files
+				.iter()
+				.map(|file| normalize_path(Path::new(file.as_ref())))
+				.collect(),
+		}
+	}
+
+	/// Resolve the given specifier found in the given file of the example
+	///
+	/// This returns `None` if the specifier doesn't have to change.
+	pub(super) fn resolve(&self, file: &str, specifier: &str) -> Option<String> {
+		if !specifier.starts_with("./") && !specifier.starts_with("../") {
+			return None;
+		}
+
+		let file_folder = Path::new(file).parent().unwrap_or(Path::new(""));
+
+		let in_example = normalize_path(&file_folder.join(specifier));
+		if self.is_file_in_example(&in_example) {
+			return None;
+		}
+
+		let target = normalize_path(&self.chapter_folder.join(file_folder).join(specifier));
+		let relative = diff_paths(target, self.example_folder.join(file_folder))?;
+		let relative = relative.to_string_lossy().replace('\\', "/");
+
+		Some(if relative.starts_with("../") {
+			relative
+		} else {
+			format!("./{relative}")
+		})
+	}
+
+	fn is_file_in_example(&self, path: &Path) -> bool {
+		let path = path.to_string_lossy();
+
+		self.files.iter().any(|file| {
+			let file = file.to_string_lossy();
+
+			file == path
+				|| EXTENSIONS
+					.iter()
+					.any(|ext| file.strip_suffix(ext) == Some(path.as_ref()))
+				|| path
+					.strip_suffix(".js")
+					.is_some_and(|path| file.strip_suffix(".ts") == Some(path))
+		})
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use std::path::Path;
+
+	use super::PathResolver;
+
+	#[test]
+	fn test_single_file() {
+		let resolver = PathResolver::new(
+			Path::new("/book/mdbook_angular"),
+			Path::new("/book/src/chapter").to_owned(),
+		);
+
+		assert_eq!(
+			Some("../../src/chapter/helpers"),
+			resolver.resolve("codeblock.ts", "./helpers").as_deref()
+		);
+		assert_eq!(
+			Some("../../src/shared/template.html"),
+			resolver
+				.resolve("codeblock.ts", "../shared/template.html")
+				.as_deref()
+		);
+		assert_eq!(None, resolver.resolve("codeblock.ts", "@angular/core"));
+	}
+
+	#[test]
+	fn test_multiple_files() {
+		let resolver = PathResolver::new(
+			Path::new("/book/mdbook_angular"),
+			Path::new("/book/src/chapter").to_owned(),
+		)
+		.for_files(&["app.component.ts", "app.component.html", "lib/util.ts"]);
+
+		assert_eq!(None, resolver.resolve("app.component.ts", "./lib/util"));
+		assert_eq!(None, resolver.resolve("app.component.ts", "./lib/util.js"));
+		assert_eq!(
+			None,
+			resolver.resolve("app.component.ts", "./app.component.html")
+		);
+		assert_eq!(None, resolver.resolve("lib/util.ts", "../app.component"));
+		assert_eq!(
+			Some("../../../src/chapter/helpers"),
+			resolver.resolve("app.component.ts", "./helpers").as_deref()
+		);
+		assert_eq!(
+			Some("../../../../src/chapter/helpers"),
+			resolver.resolve("lib/util.ts", "../helpers").as_deref()
+		);
+	}
+}
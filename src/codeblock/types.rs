@@ -1,12 +1,73 @@
-use std::rc::Rc;
+use std::{collections::BTreeMap, path::PathBuf, rc::Rc};
 
-use super::playground::Playground;
+use serde_json::Value;
+
+use crate::Layout;
+
+use super::playground::{Playground, PlaygroundInputConfig};
+
+pub(crate) struct PrintedFile {
+	/// Name of the file
+	pub(crate) name: String,
+	/// Language of the file, used to highlight the code
+	pub(crate) language: String,
+	/// The code to show in a markdown code block
+	pub(crate) code: Rc<String>,
+	/// The code including hidden lines marked with the hidden line prefix, if
+	/// the file has hidden lines and these can be toggled
+	pub(crate) code_with_hidden_lines: Option<Rc<String>>,
+}
 
 pub(crate) struct PrintedCodeBlock {
 	/// The code to show in a markdown code block
 	pub(crate) code: Rc<String>,
+	/// The code including hidden lines marked with the hidden line prefix, if
+	/// the code has hidden lines and these can be toggled
+	pub(crate) code_with_hidden_lines: Option<Rc<String>>,
 	/// Whether to show the code block collapsed or not
 	pub(crate) collapsed: bool,
+	/// Name of the file containing the code, if the code comes from a file
+	pub(crate) name: Option<String>,
+	/// All files to show, if the code block consists of multiple files
+	///
+	/// This includes the file in [`Self::code`].
+	pub(crate) files: Vec<PrintedFile>,
+	/// Templates and stylesheets referenced by the components via
+	/// `templateUrl`, `styleUrl` or `styleUrls`
+	pub(crate) resources: Vec<PrintedFile>,
+}
+
+/// A file to write to disk next to the bootstrapped code of a [`CodeBlock`]
+pub(crate) struct CodeFile {
+	/// Name of the file, relative to the other files of the code block
+	pub(crate) name: String,
+	/// Contents of the file
+	pub(crate) code: Rc<String>,
+}
+
+/// An angular component to bootstrap
+pub(crate) struct Component {
+	/// Name of the exported component class
+	pub(crate) class_name: String,
+	/// The tag name of the root element
+	pub(crate) tag: String,
+	/// Names and configuration of the inputs declared by the component, even if
+	/// the playground isn't shown
+	pub(crate) inputs: Vec<(String, PlaygroundInputConfig)>,
+	/// Playground for the live angular component, if enabled and present
+	pub(crate) playground: Option<Playground>,
+}
+
+/// The number and caption of an example, making it possible to refer to the
+/// example from anywhere in the book
+pub(crate) struct ExampleLabel {
+	/// Number of the example within the book, e.g. `3.2` for the second
+	/// numbered example in chapter 3
+	pub(crate) number: String,
+	/// Caption of the example, if any
+	pub(crate) caption: Option<String>,
+	/// Anchor of the example on the page
+	pub(crate) anchor: String,
 }
 
 /// A block of angular code that will be shown with a live code sample
@@ -16,15 +77,36 @@ pub struct CodeBlock {
 
 	/// An entire TypeScript file to write to disk
 	pub(crate) code_to_run: Rc<String>,
-	/// Name of the angular component exported in `code_to_run` that should be
-	/// bootstrapped
-	pub(crate) class_name: String,
+	/// The angular components exported in `code_to_run` that should be
+	/// bootstrapped, each as a separate application
+	pub(crate) components: Vec<Component>,
+
+	/// Name of the file to write `code_to_run` into, if the code block consists
+	/// of multiple files
+	pub(crate) file_name: Option<String>,
+	/// Other files to write next to `code_to_run`
+	pub(crate) files: Vec<CodeFile>,
 
-	/// Whether to insert the element angular will bootstrap into the page
+	/// Whether to insert the elements angular will bootstrap into the page
 	pub(crate) insert: bool,
-	/// The tag name of the root element
-	pub(crate) tag: String,
+	/// How to position the code relative to the live example
+	pub(crate) layout: Layout,
 
-	/// Playground for the live angular component, if enabled and present
-	pub(crate) playground: Option<Playground>,
+	/// Initial values of inputs, set right after bootstrapping the component
+	pub(crate) inputs: Vec<(String, Value)>,
+
+	/// Language of the code block, e.g. `ts`
+	pub(crate) language: String,
+	/// Names of all flags passed to the code block, excluding attributes and
+	/// inputs
+	pub(crate) flags: Vec<&'static str>,
+	/// Path to the file shown via an `{{#angular}}` tag, if any
+	pub(crate) source_path: Option<PathBuf>,
+
+	/// Attributes passed via `key=value` flags
+	pub(crate) attributes: BTreeMap<String, String>,
+	/// Number and caption of the example, if it has a caption or id
+	///
+	/// Examples are numbered per chapter while rendering the chapter.
+	pub(crate) label: Option<ExampleLabel>,
 }
@@ -1,11 +1,15 @@
-use std::path::{Path, PathBuf};
+use std::{
+	collections::BTreeMap,
+	path::{Path, PathBuf},
+	str::FromStr,
+};
 
 use anyhow::Context;
 use mdbook::renderer::RenderContext;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use toml::value::Table;
 
-use crate::Result;
+use crate::{strings::get_strings, Result};
 
 #[derive(Deserialize, PartialEq, Eq, Debug, Default, Clone, Copy)]
 #[serde(rename_all = "lowercase")]
@@ -32,6 +36,35 @@ pub enum Builder {
 	Slow,
 }
 
+/// How to position the code of an example relative to the live example and its
+/// playground
+#[derive(Deserialize, Serialize, PartialEq, Eq, Debug, Default, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub enum Layout {
+	/// Show the code above the live example
+	#[default]
+	Stacked,
+	/// Show the code next to the live example, if there's enough room
+	SideBySide,
+	/// Show the live example above the code
+	PreviewFirst,
+}
+
+impl FromStr for Layout {
+	type Err = crate::Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		match s {
+			"stacked" => Ok(Self::Stacked),
+			"side-by-side" => Ok(Self::SideBySide),
+			"preview-first" => Ok(Self::PreviewFirst),
+			_ => Err(crate::Error::msg(format!(
+				"Invalid layout {s}, expected stacked, side-by-side or preview-first"
+			))),
+		}
+	}
+}
+
 #[derive(Deserialize)]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
 struct DeConfig {
@@ -42,16 +75,24 @@ struct DeConfig {
 	builder: Builder,
 	collapsed: Option<bool>,
 	playgrounds: Option<bool>,
+	hidden_line_prefix: Option<String>,
+	hidden_lines_toggle: Option<bool>,
+	#[serde(default)]
+	layout: Layout,
+	theme_stylesheet: Option<bool>,
 	tsconfig: Option<PathBuf>,
+	aliases: Option<BTreeMap<String, PathBuf>>,
 	inline_style_language: Option<String>,
 	optimize: Option<bool>,
 	polyfills: Option<Vec<String>>,
 	workdir: Option<String>,
+	strings: Option<BTreeMap<String, String>>,
 
 	html: Option<Table>,
 }
 
 /// Configuration for mdbook-angular
+#[allow(clippy::struct_excessive_bools)]
 pub struct Config {
 	/// Builder to use to compile the angular code
 	///
@@ -74,8 +115,41 @@ pub struct Config {
 	///
 	/// Default value: `true`
 	pub playgrounds: bool,
+	/// Prefix that marks lines in code blocks as hidden
+	///
+	/// Hidden lines are part of the code that runs, but they're not shown on
+	/// the page. Set this to an empty string to disable hidden lines.
+	///
+	/// Default value: `"#!"`
+	pub hidden_line_prefix: String,
+	/// Whether to show a button on code blocks with hidden lines to toggle the
+	/// hidden lines
+	///
+	/// Default value: `false`
+	pub hidden_lines_toggle: bool,
+	/// Layout of examples
+	///
+	/// This can be overridden via the `layout=` attribute on every individual
+	/// code block or `{{#angular}}` tag.
+	///
+	/// Default value: [`Layout::Stacked`]
+	pub layout: Layout,
+	/// Whether to style the playgrounds using the colours of mdbook's themes
+	///
+	/// Disable this to style the playgrounds yourself, e.g. via the
+	/// `additional-css` option of the HTML renderer.
+	///
+	/// Default value: `true`
+	pub theme_stylesheet: bool,
 	/// Path to a tsconfig to use for building, relative to the `book.toml` file
 	pub tsconfig: Option<PathBuf>,
+	/// Import aliases, mapping a prefix onto a folder inside the book's source
+	/// folder
+	///
+	/// An alias `"@book"` pointing to folder `"shared"` makes an import of
+	/// `"@book/lorem"` resolve to the file `lorem.ts` inside the `shared` folder
+	/// of the book's source folder.
+	pub aliases: BTreeMap<String, PathBuf>,
 	/// The inline style language the angular compiler should use
 	///
 	/// Default value: `"css"`
@@ -92,6 +166,12 @@ pub struct Config {
 	///
 	/// This only supports bare specifiers, you can't add relative imports here.
 	pub polyfills: Vec<String>,
+	/// Strings shown by the template, e.g. `"show_code"`
+	///
+	/// This contains the built-in translations for the book's language,
+	/// falling back to English, overridden by the `strings` table in the
+	/// configuration.
+	pub strings: BTreeMap<String, String>,
 
 	/// Configuration to pass to the HTML renderer
 	///
@@ -160,10 +240,21 @@ impl Config {
 			builder: de_config.builder,
 			collapsed: de_config.collapsed.unwrap_or(false),
 			playgrounds: de_config.playgrounds.unwrap_or(true),
+			hidden_line_prefix: de_config
+				.hidden_line_prefix
+				.map_or_else(|| "#!".to_owned(), |prefix| prefix.trim().to_owned()),
+			hidden_lines_toggle: de_config.hidden_lines_toggle.unwrap_or(false),
+			layout: de_config.layout,
+			theme_stylesheet: de_config.theme_stylesheet.unwrap_or(true),
 			tsconfig: de_config.tsconfig.map(|tsconfig| root.join(tsconfig)),
+			aliases: de_config.aliases.unwrap_or_default(),
 			inline_style_language: de_config.inline_style_language.unwrap_or("css".to_owned()),
 			optimize: de_config.optimize.unwrap_or(false),
 			polyfills: de_config.polyfills.unwrap_or_default(),
+			strings: get_strings(
+				config.book.language.as_deref(),
+				de_config.strings.unwrap_or_default(),
+			),
 
 			html: de_config.html,
 
@@ -0,0 +1,2 @@
+pub(crate) static STYLESHEET: &str = include_str!("mdbook-angular.css");
+pub(crate) static THEME_STYLESHEET: &str = include_str!("mdbook-angular-theme.css");
@@ -5,6 +5,47 @@ let create = name => document.createElement(name);
 let attr = (self, name) => self.getAttribute(name);
 let on = (element, name, listener) => element.addEventListener(name, listener);
 
+let serialize = value => {
+	try {
+		return JSON.stringify(value) ?? String(value);
+	} catch {
+		// e.g. circular values
+		return String(value);
+	}
+};
+
+/** Input types for the string formats */
+let inputTypes = {
+	date: 'date',
+	datetime: 'datetime-local',
+	color: 'color',
+	url: 'url',
+};
+let dateLengths = {date: 10, datetime: 16};
+
+/** Value of a date or datetime input for the given `Date` or string */
+let toDateValue = (value, format) => {
+	if (value instanceof Date) {
+		if (Number.isNaN(value.getTime())) {
+			return '';
+		}
+
+		// Dates are read as UTC, datetimes as local time
+		const offset =
+			format === 'datetime' ? value.getTimezoneOffset() * 60000 : 0;
+		value = new Date(value.getTime() - offset).toISOString();
+	}
+
+	return (value ?? '').slice(0, dateLengths[format]);
+};
+
+/** @param {HTMLInputElement} input */
+let setRange = (input, {min, max, step}) => {
+	if (min != null) input.min = min;
+	if (max != null) input.max = max;
+	input.step = step ?? 'any';
+};
+
 customElements.define(
 	'mdbook-angular-input',
 	class MdbookAngularInputElement extends HTMLElement {
@@ -19,7 +60,9 @@ customElements.define(
 			const config = JSON.parse(this.innerText);
 
 			let input;
+			let element;
 			let getValue;
+			let setValue;
 
 			if (typeof config.type === 'object' && 'enum' in config.type) {
 				input = create('select');
@@ -28,51 +71,143 @@ customElements.define(
 						const option = create('option');
 						option.value = value;
 						option.innerText = value;
-						option.selected = value === config.default;
 						return option;
 					}),
 				);
 
 				getValue = () => input.value;
+				setValue = value => {
+					for (const option of input.options) {
+						option.selected = option.value === value;
+					}
+				};
 			} else {
 				switch (config.type) {
 					case 'number': {
 						input = create('input');
 						input.type = 'number';
-						input.valueAsNumber = config.default;
+						setRange(input, config);
+
+						getValue = () => {
+							const value = input.valueAsNumber;
+
+							if (Number.isNaN(value)) {
+								throw new Error('Invalid number');
+							}
+
+							return value;
+						};
 
-						getValue = () => input.valueAsNumber;
+						// A slider requires both bounds
+						let range;
+						if (config.min != null && config.max != null) {
+							range = create('input');
+							range.type = 'range';
+							setRange(range, config);
+
+							on(range, 'input', () => {
+								input.valueAsNumber = range.valueAsNumber;
+								input.dispatchEvent(new Event('input'));
+							});
+							on(range, 'change', () => {
+								input.dispatchEvent(new Event('change'));
+							});
+							on(input, 'input', () => {
+								if (input.validity.valid) {
+									range.valueAsNumber = input.valueAsNumber;
+								}
+							});
+
+							element = create('span');
+							element.className = 'mdbook-angular-range';
+							element.append(range, input);
+						}
+
+						setValue = value => {
+							input.valueAsNumber = value;
+							if (range) {
+								range.valueAsNumber = value ?? config.min;
+							}
+						};
 						break;
 					}
 					case 'boolean': {
 						input = create('input');
 						input.type = 'checkbox';
-						input.checked = config.default;
 
 						getValue = () => input.checked;
+						setValue = value => {
+							input.checked = value;
+						};
+						break;
+					}
+					case 'json': {
+						input = create('textarea');
+						input.rows = 4;
+						input.spellcheck = false;
+
+						// Throws if the value isn't valid JSON
+						getValue = () => JSON.parse(input.value);
+						setValue = value => {
+							input.value = JSON.stringify(value ?? null, null, 2);
+						};
 						break;
 					}
+					case 'date':
 					default: {
-						input = create('input');
-						input.type = 'text';
-						input.value = config.default || '';
+						const format =
+							config.format ?? (config.type === 'date' ? 'date' : null);
+
+						if (format === 'multiline') {
+							input = create('textarea');
+							input.rows = 4;
+						} else {
+							input = create('input');
+							input.type = inputTypes[format] ?? 'text';
+						}
 
-						getValue = () => input.value;
+						getValue =
+							config.type === 'date'
+								? () => (input.value ? new Date(input.value) : null)
+								: () => input.value;
+						setValue = value => {
+							input.value =
+								format in dateLengths
+									? toDateValue(value, format)
+									: value || '';
+						};
 						break;
 					}
 				}
 			}
 
+			setValue(config.default);
+
 			while (this.firstChild) {
 				this.firstChild.remove();
 			}
 
-			this.append(input);
+			this.append(element ?? input);
 
 			const name = attr(this, 'name');
 			const index = +attr(this, 'index');
 
 			function update() {
+				let value;
+				try {
+					input.setCustomValidity('');
+					value = getValue();
+
+					// Don't pass values breaking e.g. the min, max or url format to the component
+					if (!input.validity.valid) {
+						throw new Error(input.validationMessage);
+					}
+				} catch (e) {
+					// Only pass valid values to the component
+					input.setCustomValidity(e.message);
+					return;
+				}
+
 				let app =
 					/** @type {Promise<import('@angular/core').ApplicationRef>} */ (
 						mdBookAngular.applications[index]
@@ -85,7 +220,7 @@ customElements.define(
 					const component = app.components[0];
 
 					zone.run(() => {
-						component.setInput(name, getValue());
+						component.setInput(name, value);
 					});
 				});
 			}
@@ -102,6 +237,23 @@ customElements.define(
 
 			on(input, 'change', update);
 			on(input, 'input', throttledUpdate);
+
+			// Show the values a component sets on its own model
+			const model = attr(this, 'model');
+			if (model != null) {
+				let app =
+					/** @type {Promise<import('@angular/core').ApplicationRef>} */ (
+						mdBookAngular.applications[index]
+					);
+
+				app.then(app => {
+					// Model signals emit their ...Change output via subscribe
+					app.components[0].instance[model]?.subscribe(value => {
+						setValue(value);
+						input.setCustomValidity('');
+					});
+				});
+			}
 		}
 	},
 );
@@ -150,3 +302,105 @@ customElements.define(
 		}
 	},
 );
+
+customElements.define(
+	'mdbook-angular-events',
+	class MdbookAngularEventsElement extends HTMLElement {
+		#processed = false;
+
+		connectedCallback() {
+			if (this.#processed) {
+				return;
+			}
+			this.#processed = true;
+
+			/** @type {{name: string, property: string}[]} */
+			const outputs = JSON.parse(this.innerText);
+			const index = +attr(this, 'index');
+
+			while (this.firstChild) {
+				this.firstChild.remove();
+			}
+
+			const log = create('ol');
+			const clear = create('button');
+			clear.type = 'button';
+			clear.append(attr(this, 'clear'));
+			on(clear, 'click', () => log.replaceChildren());
+			this.append(log, clear);
+
+			let app =
+				/** @type {Promise<import('@angular/core').ApplicationRef>} */ (
+					mdBookAngular.applications[index]
+				);
+
+			app.then(app => {
+				const component = app.components[0];
+
+				for (const {name, property} of outputs) {
+					// Both EventEmitter and OutputEmitterRef have a subscribe method
+					component.instance[property]?.subscribe(value => {
+						const now = new Date();
+						const time = create('time');
+						time.dateTime = now.toISOString();
+						time.append(now.toLocaleTimeString());
+
+						const code = create('code');
+						code.append(`${name}: ${serialize(value)}`);
+
+						const entry = create('li');
+						entry.append(time, ' ', code);
+						log.append(entry);
+						log.scrollTop = log.scrollHeight;
+					});
+				}
+			});
+		}
+	},
+);
+
+customElements.define(
+	'mdbook-angular-tabs',
+	class MdbookAngularTabsElement extends HTMLElement {
+		#processed = false;
+
+		connectedCallback() {
+			if (this.#processed) {
+				return;
+			}
+			this.#processed = true;
+
+			const tabs = [...this.children].filter(child =>
+				child.classList.contains('mdbook-angular-tab'),
+			);
+
+			if (tabs.length < 2) {
+				return;
+			}
+
+			const list = create('div');
+			list.className = 'mdbook-angular-tab-list';
+			list.setAttribute('role', 'tablist');
+
+			const buttons = tabs.map((tab, index) => {
+				const button = create('button');
+				button.type = 'button';
+				button.append(attr(tab, 'data-name'));
+				button.setAttribute('role', 'tab');
+				on(button, 'click', () => select(index));
+				return button;
+			});
+
+			function select(selected) {
+				tabs.forEach((tab, index) => {
+					tab.hidden = index !== selected;
+					buttons[index].setAttribute('aria-selected', String(index === selected));
+				});
+			}
+
+			list.append(...buttons);
+			this.prepend(list);
+			select(0);
+		}
+	},
+);
@@ -16,8 +16,11 @@
 mod angular;
 pub(crate) mod codeblock;
 pub(crate) mod config;
+mod css;
 mod js;
 mod markdown;
+mod strings;
+mod template;
 mod utils;
 
 /// The version of mdbook-angular
@@ -32,17 +35,18 @@ pub const EXPECTED_MDBOOK_VERSION: &str = mdbook::MDBOOK_VERSION;
 use std::{env, fs};
 
 pub use angular::stop_background_process;
-pub use config::{Builder, Config};
+pub use config::{Builder, Config, Layout};
 
 use angular::build;
 use log::debug;
 use log::warn;
-use markdown::process_markdown;
 use markdown::ChapterWithCodeBlocks;
+use markdown::{collect_references, expand_references, process_markdown};
 use mdbook::{
 	renderer::{HtmlHandlebars, RenderContext},
 	BookItem, Renderer,
 };
+use template::create_handlebars;
 
 fn validate_version(ctx: &RenderContext) -> Result<()> {
 	let req = semver::VersionReq::parse(EXPECTED_MDBOOK_VERSION).unwrap();
@@ -90,6 +94,7 @@ impl AngularRenderer {
 		validate_version(ctx)?;
 
 		let config = Config::new(ctx)?;
+		let handlebars = create_handlebars(&config)?;
 		let mut chapters_with_codeblocks = Vec::new();
 		let mut result: Result<()> = Ok(());
 
@@ -100,7 +105,7 @@ impl AngularRenderer {
 
 			if let BookItem::Chapter(chapter) = item {
 				debug!("Processing chapter {}", &chapter.name);
-				match process_markdown(&config, chapter) {
+				match process_markdown(&config, &handlebars, chapter) {
 					Ok(processed) => {
 						debug!("Processed chapter {}", &chapter.name);
 						if let Some(processed) = processed {
@@ -112,6 +117,26 @@ impl AngularRenderer {
 			}
 		});
 
+		result?;
+
+		// References can point to examples in any chapter, so they can only be
+		// expanded once all chapters have been processed
+		let references = collect_references(&chapters_with_codeblocks)?;
+		let mut result: Result<()> = Ok(());
+
+		ctx.book.for_each_mut(|item| {
+			if result.is_err() {
+				return;
+			}
+
+			if let BookItem::Chapter(chapter) = item {
+				result =
+					expand_references(&config, chapter, &chapters_with_codeblocks, &references);
+			}
+		});
+
+		result?;
+
 		debug!("Processed chapters");
 
 		if let Some(html) = &config.html {
@@ -124,6 +149,14 @@ impl AngularRenderer {
 			config.target_folder.join("playground-io.min.js"),
 			crate::js::PLAYGROUND_SCRIPT,
 		)?;
+		fs::write(
+			config.target_folder.join("mdbook-angular.css"),
+			crate::css::STYLESHEET,
+		)?;
+		fs::write(
+			config.target_folder.join("mdbook-angular-theme.css"),
+			crate::css::THEME_STYLESHEET,
+		)?;
 
 		debug!("Finished rendering");
 
@@ -132,7 +165,13 @@ impl AngularRenderer {
 
 		#[cfg(debug_assertions)]
 		if env::var("MDBOOK_ANGULAR_SKIP_BUILD").is_ok() {
-			run_build = false;
+			// Tests inspect the written angular project without building it
+			if run_build {
+				angular::write_project(&config, chapters_with_codeblocks)?;
+			}
+
+			debug!("Finished");
+			return Ok(());
 		}
 
 		if run_build {
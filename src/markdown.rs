@@ -1,8 +1,9 @@
 extern crate alloc;
 
 use std::{
-	fs,
-	ops::Deref,
+	collections::{BTreeMap, HashMap, HashSet},
+	fs, iter,
+	ops::{Deref, Range},
 	path::{Path, PathBuf},
 	rc::Rc,
 };
@@ -12,23 +13,39 @@ use handlebars::Handlebars;
 use mdbook::book::Chapter;
 use once_cell::sync::Lazy;
 use pathdiff::diff_paths;
-use pulldown_cmark::{CodeBlockKind, CowStr, Event, Options, Parser, Tag, TagEnd};
-use pulldown_cmark_to_cmark::cmark as markdown_to_string;
-use regex::Regex;
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+use regex::{Captures, Regex};
 use serde::Serialize;
 
 use crate::{
-	codeblock::{is_angular_codeblock, to_codeblock, CodeBlock},
+	bail,
+	codeblock::{
+		find_callout_explanations, is_angular_codeblock, is_grouped_codeblock, replace_callouts,
+		to_codeblock, to_codeblock_group, CodeBlock, CodeBlockSource, Component, ExampleLabel,
+		PathResolver, PrintedFile,
+	},
+	template::{get_template_name, shows_callouts},
 	utils::path_to_root,
-	Config, Error, Result,
+	Config, Layout, Result,
 };
 
+static TAG_ANGULAR: Lazy<Regex> = Lazy::new(|| {
+	Regex::new(r"\{\{#angular\s+(?<path>[^\s#:}]+)(?:#(?<class_name>[^\s:}]+))?(?::(?<excerpt>[^\s}]+))?(?<flags>\s+(?:[^}]|\}[^}])*?)?\}\}")
+		.unwrap()
+});
+
+/// An `{{#angular-ref id}}` or `{{#angular-examples}}` tag
+static TAG_ANGULAR_REFERENCE: Lazy<Regex> =
+	Lazy::new(|| Regex::new(r"\{\{#angular-(?:ref\s+(?<id>[^\s}]+)|examples)\s*\}\}").unwrap());
+
 #[derive(Serialize)]
 struct CodeBlockTemplateInput {
 	name: String,
 
 	description: Option<String>,
 
+	description_html: Option<String>,
+
 	value: String,
 }
 
@@ -37,324 +54,912 @@ struct CodeBlockTemplateAction {
 	button: String,
 
 	description: String,
+
+	description_html: String,
 }
 
 #[derive(Serialize)]
-struct CodeBlockTemplateFlags {
-	collapsed: bool,
+struct CodeBlockTemplateOutput {
+	name: String,
+
+	description: Option<String>,
+
+	description_html: Option<String>,
+}
+
+#[derive(Serialize)]
+struct CodeBlockTemplateFile {
+	name: String,
+
+	language: String,
+
+	code: String,
+
+	code_with_hidden_lines: Option<String>,
+}
+
+impl From<&PrintedFile> for CodeBlockTemplateFile {
+	fn from(file: &PrintedFile) -> Self {
+		Self {
+			name: file.name.clone(),
+			language: file.language.clone(),
+			code: Rc::deref(&file.code).clone(),
+			code_with_hidden_lines: file
+				.code_with_hidden_lines
+				.as_deref()
+				.map(ToOwned::to_owned),
+		}
+	}
+}
+
+#[derive(Serialize)]
+struct CodeBlockTemplateCallout {
+	number: u32,
+
+	text: String,
+
+	text_html: String,
+}
+
+impl From<(u32, String)> for CodeBlockTemplateCallout {
+	fn from((number, text): (u32, String)) -> Self {
+		Self {
+			number,
+			text_html: render_description(&text),
+			text,
+		}
+	}
+}
+
+#[derive(Serialize)]
+struct CodeBlockTemplateChapter {
+	name: String,
+
+	path: Option<PathBuf>,
 }
 
 #[derive(Serialize)]
 struct CodeBlockTemplateData {
+	chapter: CodeBlockTemplateChapter,
+
+	index: usize,
+
+	application_index: usize,
+
+	class_name: String,
+
+	tag: String,
+
+	source_path: Option<PathBuf>,
+
+	language: String,
+
 	playground: String,
 
 	code: Option<String>,
 
+	code_with_hidden_lines: Option<String>,
+
+	hidden_line_prefix: String,
+
+	name: Option<String>,
+
+	files: Vec<CodeBlockTemplateFile>,
+
+	resources: Vec<CodeBlockTemplateFile>,
+
+	callouts: Vec<CodeBlockTemplateCallout>,
+
 	inputs: Vec<CodeBlockTemplateInput>,
 
 	actions: Vec<CodeBlockTemplateAction>,
 
-	flags: CodeBlockTemplateFlags,
+	outputs: Vec<CodeBlockTemplateOutput>,
+
+	event_log: Option<String>,
+
+	flags: BTreeMap<&'static str, bool>,
+
+	layout: Layout,
+
+	number: Option<String>,
+
+	caption: Option<String>,
+
+	anchor: Option<String>,
+
+	attributes: BTreeMap<String, String>,
+
+	strings: BTreeMap<String, String>,
 }
 
 impl CodeBlockTemplateData {
-	fn new(index: usize, code_block: &CodeBlock) -> Self {
-		let mut flags = CodeBlockTemplateFlags { collapsed: false };
+	/// Create the template data for a single component of a code block
+	///
+	/// Only the first component of a code block shows the code, the other
+	/// components only show their playground.
+	fn new(collector: &CodeBlockCollector, code_block: &CodeBlock, component_index: usize) -> Self {
+		let config = collector.config;
+		let component = &code_block.components[component_index];
+		let first = component_index == 0;
+
+		// Every component is bootstrapped as a separate application, the index
+		// is the position of the application in the list of all applications
+		let index = collector
+			.code_blocks
+			.iter()
+			.map(|code_block| code_block.components.len())
+			.sum::<usize>()
+			+ component_index;
+
+		let mut flags: BTreeMap<&'static str, bool> =
+			code_block.flags.iter().map(|&flag| (flag, true)).collect();
+		flags.insert("collapsed", false);
 		let mut code = None;
-
-		if let Some(printed_code) = &code_block.code_to_print {
+		let mut code_with_hidden_lines = None;
+		let mut name = None;
+		let mut files: Vec<CodeBlockTemplateFile> = Vec::new();
+		let mut resources: Vec<CodeBlockTemplateFile> = Vec::new();
+		let mut attributes = code_block.attributes.clone();
+
+		if !first {
+			attributes.remove("id");
+		} else if let Some(printed_code) = &code_block.code_to_print {
 			code = Some(Rc::deref(&printed_code.code).clone());
-			flags.collapsed = printed_code.collapsed;
+			code_with_hidden_lines = printed_code
+				.code_with_hidden_lines
+				.as_deref()
+				.map(ToOwned::to_owned);
+			flags.insert("collapsed", printed_code.collapsed);
+
+			name.clone_from(&printed_code.name);
+			files = printed_code.files.iter().map(Into::into).collect();
+			resources = printed_code.resources.iter().map(Into::into).collect();
 		}
 
-		let playground = if code_block.insert {
-			format!("<{0}></{0}>\n", code_block.tag)
+		let mut callout_texts = if first {
+			collector.callout_explanations.clone()
 		} else {
-			String::new()
+			BTreeMap::new()
 		};
+		if code.is_some() {
+			let mut replace =
+				|code: &mut String| *code = replace_callouts(code, &mut callout_texts);
+
+			code.iter_mut()
+				.chain(code_with_hidden_lines.iter_mut())
+				.for_each(&mut replace);
+
+			for file in files.iter_mut().chain(resources.iter_mut()) {
+				replace(&mut file.code);
+				file.code_with_hidden_lines
+					.iter_mut()
+					.for_each(&mut replace);
+			}
+		}
 
-		let mut inputs = Vec::new();
-		let mut actions = Vec::new();
-
-		if let Some(playground) = &code_block.playground {
-			for input in &playground.inputs {
-				let value = format!(
-					"<mdbook-angular-input name=\"{}\" index=\"{}\">{}</mdbook-angular-input>",
-					input.name,
-					index,
-					serde_json::to_string(&input.config)
-						.unwrap()
-						.replace('<', "&lt;")
-				);
+		let callouts = callout_texts.into_iter().map(Into::into).collect();
 
-				inputs.push(CodeBlockTemplateInput {
-					name: input.name.clone(),
-					description: input.description.clone(),
-					value,
-				});
-			}
+		let label = code_block.label.as_ref().filter(|_| first);
 
-			for action in &playground.actions {
-				let button = format!(
-					"<mdbook-angular-action name=\"{}\" index=\"{}\"></mdbook-angular-action>",
-					action.name, index
-				);
+		let playground = if code_block.insert {
+			format!("<{0}></{0}>\n", component.tag)
+		} else {
+			String::new()
+		};
 
-				actions.push(CodeBlockTemplateAction {
-					button,
-					description: action.description.clone(),
-				});
-			}
-		}
+		let PlaygroundRows {
+			inputs,
+			actions,
+			outputs,
+			event_log,
+		} = get_playground_rows(config, component, index);
 
 		Self {
+			chapter: CodeBlockTemplateChapter {
+				name: collector.chapter.name.clone(),
+				path: collector.chapter.path.clone(),
+			},
+			index: collector.code_blocks.len(),
+			application_index: index,
+			class_name: component.class_name.clone(),
+			tag: component.tag.clone(),
+			source_path: code_block.source_path.as_ref().map(|path| {
+				path.strip_prefix(&config.book_source_folder)
+					.unwrap_or(path)
+					.to_path_buf()
+			}),
+			language: code_block.language.clone(),
 			playground,
 			code,
+			code_with_hidden_lines,
+			hidden_line_prefix: config.hidden_line_prefix.clone(),
+			name,
+			files,
+			resources,
+			callouts,
 			inputs,
 			actions,
+			outputs,
+			event_log,
 			flags,
+			layout: code_block.layout,
+			number: label.map(|label| label.number.clone()),
+			caption: label.and_then(|label| label.caption.clone()),
+			anchor: label.map(|label| label.anchor.clone()),
+			attributes,
+			strings: config.strings.clone(),
+		}
+	}
+}
+
+#[derive(Default)]
+struct PlaygroundRows {
+	inputs: Vec<CodeBlockTemplateInput>,
+	actions: Vec<CodeBlockTemplateAction>,
+	outputs: Vec<CodeBlockTemplateOutput>,
+	event_log: Option<String>,
+}
+
+/// Create the rows of the inputs, actions and outputs tables of the given
+/// component's playground, if it has one, and the log of emitted events
+fn get_playground_rows(config: &Config, component: &Component, index: usize) -> PlaygroundRows {
+	let mut rows = PlaygroundRows::default();
+
+	if let Some(playground) = &component.playground {
+		for input in &playground.inputs {
+			let model = input
+				.model
+				.as_ref()
+				.map(|model| format!(" model=\"{model}\""))
+				.unwrap_or_default();
+
+			let value = format!(
+				"<mdbook-angular-input name=\"{}\" index=\"{}\"{}>{}</mdbook-angular-input>",
+				input.name,
+				index,
+				model,
+				serde_json::to_string(&input.config)
+					.unwrap()
+					.replace('<', "&lt;")
+			);
+
+			rows.inputs.push(CodeBlockTemplateInput {
+				name: input.name.clone(),
+				description: input.description.clone(),
+				description_html: input.description.as_deref().map(render_description),
+				value,
+			});
 		}
+
+		for action in &playground.actions {
+			let button = format!(
+				"<mdbook-angular-action name=\"{}\" index=\"{}\"></mdbook-angular-action>",
+				action.name, index
+			);
+
+			rows.actions.push(CodeBlockTemplateAction {
+				button,
+				description: action.description.clone(),
+				description_html: render_description(&action.description),
+			});
+		}
+
+		for output in &playground.outputs {
+			rows.outputs.push(CodeBlockTemplateOutput {
+				name: output.name.clone(),
+				description: output.description.clone(),
+				description_html: output.description.as_deref().map(render_description),
+			});
+		}
+
+		if !playground.outputs.is_empty() {
+			let outputs: Vec<_> = playground
+				.outputs
+				.iter()
+				.map(|output| {
+					serde_json::json!({
+						"name": output.name,
+						"property": output.property,
+					})
+				})
+				.collect();
+
+			rows.event_log = Some(format!(
+				"<mdbook-angular-events index=\"{}\" clear=\"{}\">{}</mdbook-angular-events>",
+				index,
+				config.strings["clear"].replace('"', "&quot;"),
+				serde_json::to_string(&outputs)
+					.unwrap()
+					.replace('<', "&lt;")
+			));
+		}
+	}
+
+	rows
+}
+
+/// Render the given markdown to HTML
+pub(crate) fn render_markdown(markdown: &str) -> String {
+	let mut html = String::new();
+	pulldown_cmark::html::push_html(
+		&mut html,
+		Parser::new_ext(markdown.trim(), Options::ENABLE_STRIKETHROUGH),
+	);
+
+	html
+}
+
+/// Render the markdown of a description of an input or action, or of the
+/// explanation of a callout, to HTML
+///
+/// The surrounding `<p>` is left out if the description is a single paragraph,
+/// so short descriptions fit nicely into a table cell or list item.
+fn render_description(description: &str) -> String {
+	let html = render_markdown(description);
+	let html = html.trim_end();
+
+	match html
+		.strip_prefix("<p>")
+		.and_then(|html| html.strip_suffix("</p>"))
+	{
+		Some(paragraph) if !paragraph.contains("<p>") => paragraph.to_owned(),
+		_ => html.to_owned(),
 	}
 }
 
-struct CodeBlockCollector<'a, 'b> {
+/// An angular example found in the markdown of a chapter
+enum Example<'m> {
+	/// An inline code block
+	CodeBlock { language: String, code: String },
+	/// A group of consecutive inline code blocks with a file name
+	Group(Vec<(String, String)>),
+	/// An `{{#angular}}` tag
+	Tag(Captures<'m>),
+}
+
+struct CodeBlockCollector<'a> {
 	config: &'a Config,
 	chapter: &'a Chapter,
 
 	code_blocks: Vec<CodeBlock>,
 
-	current_code: Option<(String, Option<String>)>,
+	escaped_tags: HashSet<usize>,
 
-	error: Result<()>,
+	resolver: Option<PathResolver>,
 
-	handlebars: Handlebars<'b>,
-}
+	/// Explanations of the callouts of the example currently being rendered
+	callout_explanations: BTreeMap<u32, String>,
 
-impl<'a, 'c> CodeBlockCollector<'a, 'c> {
-	fn new(config: &'a Config, chapter: &'a Chapter) -> Result<Self> {
-		let mut handlebars = Handlebars::new();
+	/// Whether the example currently being rendered shows the explanations of
+	/// its callouts, otherwise they're kept in the chapter
+	shows_callouts: bool,
 
-		// Don't escape anything
-		handlebars.register_escape_fn(std::borrow::ToOwned::to_owned);
+	/// Number of examples with a caption or id found so far
+	example_count: usize,
 
-		let template_path = config.book_theme_folder.join("angular-playground.hbs");
-		if template_path.exists() {
-			handlebars.register_template_file("playground", template_path)?;
-		} else {
-			handlebars
-				.register_template_string("playground", include_str!("default_template.hbs"))?;
-		}
+	handlebars: &'a Handlebars<'a>,
+}
 
-		Ok(CodeBlockCollector {
+impl<'a> CodeBlockCollector<'a> {
+	fn new(config: &'a Config, handlebars: &'a Handlebars<'a>, chapter: &'a Chapter) -> Self {
+		let resolver = chapter
+			.path
+			.as_ref()
+			.and_then(|path| path.parent())
+			.map(|folder| {
+				PathResolver::new(
+					&config.angular_root_folder,
+					config.book_source_folder.join(folder),
+				)
+			});
+
+		// mdbook's links preprocessor removes the backslash from escaped tags
+		// before the renderer gets to see the chapter, so look at the chapter
+		// file itself to find out which tags were escaped
+		let escaped_tags = chapter
+			.source_path
+			.as_ref()
+			.and_then(|path| fs::read_to_string(config.book_source_folder.join(path)).ok())
+			.map(|source| find_escaped_tags(&source, &chapter.content, &TAG_ANGULAR))
+			.unwrap_or_default();
+
+		CodeBlockCollector {
 			config,
 			chapter,
 			code_blocks: Vec::new(),
 
-			current_code: None,
+			escaped_tags,
+
+			resolver,
+
+			callout_explanations: BTreeMap::new(),
+			shows_callouts: false,
 
-			error: Ok(()),
+			example_count: 0,
 
 			handlebars,
-		})
+		}
 	}
 
-	fn process_event<'b>(&mut self, event: Event<'b>) -> ProcessedEvent<'b> {
-		static TAG_ANGULAR: Lazy<Regex> = Lazy::new(|| {
-			Regex::new(r"\{\{#angular\s+(?<path>\S+?)(?:#(?<class_name>\S+))?(?<flags>\s+.*?)?\}\}")
-				.unwrap()
-		});
+	/// Find all examples in the given markdown, in order of appearance
+	fn find_examples<'m>(&mut self, markdown: &'m str) -> Vec<(Range<usize>, Example<'m>)> {
+		let mut examples = Vec::new();
+		let mut code_ranges = Vec::new();
+		let mut current_code: Option<(String, String)> = None;
+
+		for (event, range) in
+			Parser::new_ext(markdown, !Options::ENABLE_SMART_PUNCTUATION).into_offset_iter()
+		{
+			match event {
+				Event::Start(Tag::CodeBlock(kind)) => {
+					if let CodeBlockKind::Fenced(language) = kind {
+						if is_angular_codeblock(&language) {
+							current_code = Some((language.to_string(), String::new()));
+						}
+					}
 
-		if self.error.is_err() {
-			return ProcessedEvent::empty();
-		}
+					code_ranges.push(range);
+				}
+				Event::Text(text) => {
+					if let Some((_, code)) = &mut current_code {
+						code.push_str(&text);
+					}
+				}
+				Event::End(TagEnd::CodeBlock) => {
+					let Some((language, code)) = current_code.take() else {
+						continue;
+					};
+
+					// Leave empty code blocks as is
+					if code.is_empty() {
+						continue;
+					}
+
+					let range = code_ranges.last().unwrap().clone();
+					let range = if markdown[..range.end].ends_with('\n') {
+						range.start..(range.end - 1)
+					} else {
+						range
+					};
 
-		if let Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(language))) = &event {
-			if is_angular_codeblock(language) {
-				self.current_code = Some((language.as_ref().into(), None));
-				return ProcessedEvent::empty();
+					examples.push((range, Example::CodeBlock { language, code }));
+				}
+				Event::Start(Tag::HtmlBlock) | Event::Code(_) | Event::InlineHtml(_) => {
+					code_ranges.push(range);
+				}
+				_ => {}
 			}
 		}
 
-		if let Some((language, code)) = self.current_code.take() {
-			if let Event::Text(text) = &event {
-				self.current_code = Some((language, Some(text.to_string())));
-				return ProcessedEvent::empty();
-			}
+		for captures in TAG_ANGULAR.captures_iter(markdown) {
+			let range = captures.get(0).unwrap().range();
 
-			if let Event::End(TagEnd::CodeBlock) = &event {
-				let Some(code) = code else {
-					return ProcessedEvent::multiple(vec![
-						Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(language.into()))),
-						Event::End(TagEnd::CodeBlock),
-					]);
-				};
+			if code_ranges
+				.iter()
+				.any(|code_range| code_range.contains(&range.start))
+			{
+				continue;
+			}
 
-				return self.insert_code_block(None, None, language, &code, &Some(&code));
+			if self.escaped_tags.contains(&range.start) || markdown[..range.start].ends_with('\\') {
+				continue;
 			}
 
-			self.current_code = Some((language, code));
+			examples.push((range, Example::Tag(captures)));
 		}
 
-		let Event::Text(text) = &event else {
-			return ProcessedEvent::single(event);
-		};
-		let mut text = text.as_ref();
+		examples.sort_by_key(|(range, _)| range.start);
 
-		if !text.contains("#angular") {
-			return ProcessedEvent::single(event);
-		}
+		group_code_blocks(markdown, examples)
+	}
 
-		let Some(chapter_path) = &self.chapter.path else {
-			return ProcessedEvent::single(event);
-		};
+	/// Replace all examples in the given markdown with the rendered template
+	///
+	/// Only the examples themselves are replaced, the rest of the markdown is
+	/// kept as is.
+	fn process(&mut self, markdown: &str) -> Result<String> {
+		let mut result = String::with_capacity(markdown.len());
+		let mut last = 0;
+
+		let examples = self.find_examples(markdown);
+		let next_starts = examples
+			.iter()
+			.skip(1)
+			.map(|(range, _)| Some(range.start))
+			.chain(iter::once(None))
+			.collect::<Vec<_>>();
+
+		for ((mut range, example), next_start) in examples.into_iter().zip(next_starts) {
+			// Lines containing the next example aren't explanations of this one
+			let limit = next_start.map_or(markdown.len(), |next_start| {
+				markdown[..next_start]
+					.rfind('\n')
+					.map_or(0, |index| index + 1)
+					.max(range.end)
+			});
+			let (explanations, end) = find_callout_explanations(&markdown[..limit], range.end);
+			self.callout_explanations = explanations;
+			self.shows_callouts = false;
+
+			let html = match example {
+				Example::CodeBlock { language, code } => {
+					self.insert_code_block(None, None, None, language, &code, &Some(&code))?
+				}
+				Example::Group(group) => self.insert_code_block_group(&group)?,
+				Example::Tag(captures) => self.expand_tag(&captures)?,
+			};
 
-		let mut events = ProcessedEvent::empty();
+			if self.shows_callouts {
+				range.end = end;
+			}
 
-		while let Some(captures) = TAG_ANGULAR.captures(text) {
-			let match_ = captures.get(0).unwrap();
+			result.push_str(&markdown[last..range.start]);
+			splice_html(&mut result, markdown, range.clone(), &html);
 
-			events = events.concat(ProcessedEvent::single(Event::Text(CowStr::Boxed(
-				text[..match_.start()].to_owned().into_boxed_str(),
-			))));
+			last = range.end;
+		}
 
-			let path = self
-				.config
-				.book_source_folder
-				.join(chapter_path.parent().unwrap());
-			let path = path.join(&captures["path"]);
+		result.push_str(&markdown[last..]);
 
-			let contents = match fs::read_to_string(&path) {
-				Ok(content) => content,
-				Err(err) => {
-					self.error(Error::new(err).context(format!(
-						"Failed to read angular playground file at {} in {}",
-						&captures["path"],
-						chapter_path.display()
-					)));
+		Ok(result)
+	}
 
-					return ProcessedEvent::empty();
-				}
-			};
+	/// Expand a single `{{#angular}}` tag into the rendered code block
+	fn expand_tag(&mut self, captures: &Captures) -> Result<String> {
+		let Some(chapter_path) = self.chapter.path.as_ref() else {
+			return Ok(captures[0].to_owned());
+		};
 
-			let mut flags = vec!["ts", "angular"];
-			if let Some(flags_input) = captures.name("flags") {
-				flags.append(
-					&mut flags_input
-						.as_str()
-						.split_whitespace()
-						.collect::<Vec<&str>>(),
-				);
-			}
+		let path = self
+			.config
+			.book_source_folder
+			.join(chapter_path.parent().unwrap());
+		let path = path.join(&captures["path"]);
+
+		let contents = fs::read_to_string(&path).with_context(|| {
+			format!(
+				"Failed to read angular playground file at {} in {}",
+				&captures["path"],
+				chapter_path.display()
+			)
+		})?;
+
+		// Flags are separated by whitespace, which includes any newlines and
+		// blockquote markers if the tag spans multiple lines. The latter are
+		// ignored as unknown flags.
+		let flags = match captures.name("flags") {
+			Some(flags) => format!("ts,angular {}", flags.as_str()),
+			None => "ts,angular".to_owned(),
+		};
 
-			let reexport_path = diff_paths(
-				&path,
-				self.config.angular_root_folder.join("does_not_matter"),
-			);
+		self.insert_code_block(
+			captures.name("class_name").map(|m| m.as_str()),
+			Some(&path),
+			captures.name("excerpt").map(|m| m.as_str()),
+			flags,
+			&contents,
+			&None,
+		)
+	}
 
-			events = events.concat(self.insert_code_block(
-				captures.name("class_name").map(|m| m.as_str()),
-				reexport_path.as_deref(),
-				flags.join(","),
-				&contents,
-				&None,
-			));
+	/// Insert a group of code blocks
+	fn insert_code_block_group(&mut self, group: &[(String, String)]) -> Result<String> {
+		let index = self.code_blocks.len();
 
-			let end = match_.end();
-			if end < text.len() {
-				text = &text[(match_.end() + 1)..];
-			} else {
-				text = "";
-			}
-		}
+		let code_block = to_codeblock_group(self.config, index, self.resolver.as_ref(), group)?;
 
-		events
+		self.render_code_block(code_block)
 	}
 
-	fn insert_code_block<'b, L: AsRef<str>, C: AsRef<str>>(
+	fn insert_code_block<L: AsRef<str>, C: AsRef<str>>(
 		&mut self,
 		class_name: Option<&str>,
-		reexport_path: Option<&Path>,
+		path: Option<&Path>,
+		excerpt: Option<&str>,
 		language: L,
 		code: C,
 		code_to_print: &Option<C>,
-	) -> ProcessedEvent<'b> {
+	) -> Result<String> {
 		let index = self.code_blocks.len();
 		let language = language.as_ref();
 
-		match to_codeblock(
+		let reexport_path = path.and_then(|path| {
+			let reexport_path = diff_paths(
+				path,
+				self.config.angular_root_folder.join("does_not_matter"),
+			)?;
+
+			Some((path, reexport_path))
+		});
+
+		let source = match &reexport_path {
+			Some((path, reexport_path)) => CodeBlockSource::File {
+				path,
+				reexport_path,
+				excerpt,
+			},
+			None => CodeBlockSource::Inline {
+				resolver: self.resolver.as_ref(),
+				file: "codeblock.ts",
+			},
+		};
+
+		let code_block = to_codeblock(
 			self.config,
 			index,
 			class_name,
-			reexport_path,
+			&source,
 			language,
 			code,
 			code_to_print,
-		) {
-			Ok(code_block) => {
-				let data = CodeBlockTemplateData::new(index, &code_block);
-				self.code_blocks.push(code_block);
-
-				match self.handlebars.render("playground", &data) {
-					Ok(rendered) => {
-						// println!("got here, {rendered}");
-						ProcessedEvent::single(Event::Html(rendered.into()))
-					}
-					Err(error) => {
-						self.error(error);
-						ProcessedEvent::empty()
-					}
-				}
-			}
-			Err(error) => {
-				self.error(error);
-				ProcessedEvent::empty()
-			}
+		)?;
+
+		self.render_code_block(code_block)
+	}
+
+	fn render_code_block(&mut self, mut code_block: CodeBlock) -> Result<String> {
+		code_block.label = self.get_label(&code_block);
+
+		let template = code_block.attributes.get("template").map(String::as_str);
+		let template_name = get_template_name(template);
+		if !self.handlebars.has_template(&template_name) {
+			bail!(
+				"Unknown template {} in chapter {}, expected minimal, card, demo-only or the name of a theme/angular-playground-<name>.hbs file",
+				template.unwrap_or_default(),
+				self.chapter.name
+			);
 		}
+
+		// Only the code shows the explanations, so keep them in the chapter if
+		// the example doesn't print code
+		self.shows_callouts = code_block.code_to_print.is_some() && shows_callouts(template);
+		if !self.shows_callouts {
+			self.callout_explanations.clear();
+		}
+
+		let mut html = Vec::with_capacity(code_block.components.len());
+
+		for i in 0..code_block.components.len() {
+			let data = CodeBlockTemplateData::new(self, &code_block, i);
+			html.push(self.handlebars.render(&template_name, &data)?);
+		}
+
+		self.code_blocks.push(code_block);
+
+		Ok(html.join("\n"))
 	}
 
-	fn error<E: Into<Error>>(&mut self, error: E) {
-		if self.error.is_ok() {
-			self.error = Err(error.into());
+	/// Number the given code block if it has a caption or id
+	///
+	/// The number is prefixed with the number of the chapter, if any.
+	fn get_label(&mut self, code_block: &CodeBlock) -> Option<ExampleLabel> {
+		let id = code_block.attributes.get("id");
+		let caption = code_block.attributes.get("caption");
+
+		if id.is_none() && caption.is_none() {
+			return None;
 		}
+
+		self.example_count += 1;
+
+		let number = match &self.chapter.number {
+			Some(chapter_number) => format!("{chapter_number}{}", self.example_count),
+			None => self.example_count.to_string(),
+		};
+
+		let anchor = match id {
+			Some(id) => id.clone(),
+			None => format!("example-{}", number.replace('.', "-")),
+		};
+
+		Some(ExampleLabel {
+			number,
+			caption: caption.cloned(),
+			anchor,
+		})
 	}
 }
 
-enum ProcessedEvent<'a> {
-	Single(Option<Event<'a>>),
-	Multiple(alloc::vec::IntoIter<Event<'a>>),
-	Chain(Box<core::iter::Chain<ProcessedEvent<'a>, ProcessedEvent<'a>>>),
-}
+/// Merge consecutive code blocks with a file name into a single group
+fn group_code_blocks<'m>(
+	markdown: &str,
+	examples: Vec<(Range<usize>, Example<'m>)>,
+) -> Vec<(Range<usize>, Example<'m>)> {
+	let mut result: Vec<(Range<usize>, Example<'m>)> = Vec::with_capacity(examples.len());
+
+	for (range, example) in examples {
+		let Example::CodeBlock { language, code } = example else {
+			result.push((range, example));
+			continue;
+		};
 
-impl<'a> ProcessedEvent<'a> {
-	fn empty() -> Self {
-		Self::Single(None)
-	}
+		if !is_grouped_codeblock(&language) {
+			result.push((range, Example::CodeBlock { language, code }));
+			continue;
+		}
+
+		if let Some((last_range, Example::Group(group))) = result.last_mut() {
+			// Only whitespace and blockquote markers are allowed in between
+			if markdown[last_range.end..range.start]
+				.chars()
+				.all(|c| c.is_whitespace() || c == '>')
+			{
+				group.push((language, code));
+				last_range.end = range.end;
+				continue;
+			}
+		}
 
-	fn single(event: Event<'a>) -> Self {
-		Self::Single(Some(event))
+		result.push((range, Example::Group(vec![(language, code)])));
 	}
 
-	fn multiple(events: Vec<Event<'a>>) -> Self {
-		Self::Multiple(events.into_iter())
+	result
+}
+
+/// Find the ranges of all code and HTML in the given markdown, where tags must
+/// not be expanded
+fn find_code_ranges(markdown: &str) -> Vec<Range<usize>> {
+	Parser::new_ext(markdown, !Options::ENABLE_SMART_PUNCTUATION)
+		.into_offset_iter()
+		.filter_map(|(event, range)| match event {
+			Event::Start(Tag::CodeBlock(_) | Tag::HtmlBlock)
+			| Event::Code(_)
+			| Event::InlineHtml(_) => Some(range),
+			_ => None,
+		})
+		.collect()
+}
+
+/// Find the positions of the tags matching the given regex in the given
+/// markdown that were escaped via e.g. `\{{#angular}}` in the source of the
+/// chapter
+///
+/// The lines of the source containing escaped tags are looked up in the
+/// markdown in order, ignoring the backslashes, so tags with the same text
+/// coming from elsewhere, e.g. files included via `{{#include}}`, aren't
+/// mistaken for the escaped ones.
+fn find_escaped_tags(source: &str, markdown: &str, tag: &Regex) -> HashSet<usize> {
+	fn unescape(line: &str) -> String {
+		line.trim_end_matches(['\r', '\n']).replace("\\{{#", "{{#")
 	}
 
-	fn concat(self, other: Self) -> Self {
-		Self::Chain(Box::new(self.into_iter().chain(other)))
+	let code_ranges = find_code_ranges(source);
+	let markdown_lines: Vec<_> = lines_with_offsets(markdown).collect();
+
+	let mut escaped_tags = HashSet::new();
+	let mut next_line = 0;
+
+	for (offset, line) in lines_with_offsets(source) {
+		let escaped: Vec<_> = tag
+			.find_iter(line)
+			.enumerate()
+			.filter(|(_, match_)| {
+				line[..match_.start()].ends_with('\\')
+					&& !code_ranges
+						.iter()
+						.any(|range| range.contains(&(offset + match_.start())))
+			})
+			.map(|(index, _)| index)
+			.collect();
+
+		if escaped.is_empty() {
+			continue;
+		}
+
+		let unescaped = unescape(line);
+		let Some(found) = markdown_lines[next_line..]
+			.iter()
+			.position(|(_, markdown_line)| unescape(markdown_line) == unescaped)
+		else {
+			continue;
+		};
+
+		let (markdown_offset, markdown_line) = markdown_lines[next_line + found];
+		let matches: Vec<_> = tag.find_iter(markdown_line).collect();
+
+		escaped_tags.extend(
+			escaped
+				.into_iter()
+				.filter_map(|index| matches.get(index))
+				.map(|match_| markdown_offset + match_.start()),
+		);
+
+		next_line += found + 1;
 	}
+
+	escaped_tags
 }
 
-impl<'a> Iterator for ProcessedEvent<'a> {
-	type Item = Event<'a>;
+/// Iterate over the lines of the given text, including their line endings,
+/// together with the offset at which they start
+fn lines_with_offsets(text: &str) -> impl Iterator<Item = (usize, &str)> {
+	text.split_inclusive('\n').scan(0, |offset, line| {
+		let start = *offset;
+		*offset += line.len();
+		Some((start, line))
+	})
+}
+
+/// Write the rendered HTML of an example, replacing the given range of the
+/// markdown
+///
+/// The HTML is placed on separate lines, inside of the list items and
+/// blockquotes that contain the replaced range.
+fn splice_html(result: &mut String, markdown: &str, range: Range<usize>, html: &str) {
+	static CONTAINERS: Lazy<Regex> = Lazy::new(|| {
+		Regex::new(r"^(?:[ \t]*(?:>[ \t]?|(?:[-*+]|\d{1,9}[.)])[ \t]+))*[ \t]*").unwrap()
+	});
+
+	let line_start = markdown[..range.start]
+		.rfind('\n')
+		.map_or(0, |index| index + 1);
+	let before = &markdown[line_start..range.start];
+	let containers = CONTAINERS.find(before).map_or("", |m| m.as_str());
+
+	// Replace list markers with whitespace, but keep the blockquote markers
+	let prefix: String = containers
+		.chars()
+		.map(|c| {
+			if c == '>' || c.is_whitespace() {
+				c
+			} else {
+				' '
+			}
+		})
+		.collect();
+	let empty_line = prefix.trim_end();
+
+	if containers.len() < before.len() {
+		// The example is preceded by text on the same line
+		result.push('\n');
+		result.push_str(empty_line);
+		result.push('\n');
+		result.push_str(&prefix);
+	} else if containers == prefix && !is_blank_line(&markdown[..line_start]) {
+		// Separate the example from the previous line, unless it starts a list
+		// item, which can't start with an empty line
+		result.truncate(result.len() - before.len());
+		result.push_str(empty_line);
+		result.push('\n');
+		result.push_str(&prefix);
+	}
+
+	for (i, line) in html.trim_start_matches('\n').trim_end().lines().enumerate() {
+		if i > 0 {
+			result.push('\n');
 
-	fn next(&mut self) -> Option<Self::Item> {
-		match self {
-			Self::Single(result) => result.take(),
-			Self::Multiple(iter) => iter.next(),
-			Self::Chain(iter) => iter.next(),
+			if line.is_empty() {
+				result.push_str(empty_line);
+			} else {
+				result.push_str(&prefix);
+			}
 		}
+
+		result.push_str(line);
+	}
+
+	result.push('\n');
+	result.push_str(empty_line);
+
+	let rest_of_line = markdown[range.end..].split('\n').next().unwrap_or_default();
+	if !rest_of_line.trim().is_empty() {
+		result.push('\n');
+		result.push_str(&prefix);
 	}
 }
 
+/// Whether the last line of the given markdown is blank
+fn is_blank_line(markdown: &str) -> bool {
+	markdown
+		.trim_end_matches('\n')
+		.rsplit('\n')
+		.next()
+		.unwrap_or_default()
+		.chars()
+		.all(|c| c.is_whitespace() || c == '>')
+}
+
 pub(crate) struct ChapterWithCodeBlocks {
 	pub(crate) source_path: PathBuf,
 	pub(crate) code_blocks: Vec<CodeBlock>,
@@ -362,23 +967,16 @@ pub(crate) struct ChapterWithCodeBlocks {
 
 pub(crate) fn process_markdown(
 	config: &Config,
+	handlebars: &Handlebars,
 	chapter: &mut Chapter,
 ) -> Result<Option<ChapterWithCodeBlocks>> {
 	let Some(source_path) = chapter.source_path.clone() else {
 		return Ok(None);
 	};
 
-	let mut new_content: String = String::with_capacity(chapter.content.len());
-	let mut collector = CodeBlockCollector::new(config, chapter)?;
+	let mut collector = CodeBlockCollector::new(config, handlebars, chapter);
 
-	markdown_to_string(
-		Parser::new_ext(&chapter.content, !Options::ENABLE_SMART_PUNCTUATION)
-			.flat_map(|event| collector.process_event(event)),
-		&mut new_content,
-	)
-	.context("Failed to serialize markdown")?;
-
-	collector.error?;
+	let mut new_content = collector.process(&chapter.content)?;
 
 	let code_blocks = collector.code_blocks;
 
@@ -388,14 +986,29 @@ pub(crate) fn process_markdown(
 
 	let ptr = path_to_root(&source_path);
 
+	let theme_stylesheet = if config.theme_stylesheet {
+		format!(r#"<link rel="stylesheet" href="{ptr}/mdbook-angular-theme.css">"#)
+	} else {
+		String::new()
+	};
+
 	new_content.push_str(&format!(
-		r#"{}<script id="load-angular" data-path={} type="module" src="{}/browser/main.js"></script>"#,
+		r#"{}<link rel="stylesheet" href="{ptr}/mdbook-angular.css">{theme_stylesheet}<script id="load-angular" data-path={} type="module" src="{}/browser/main.js"></script>"#,
 		"\n\n",
 		serde_json::to_string(&source_path)?,
 		&ptr,
 	));
 
-	if code_blocks.iter().any(|b| b.playground.is_some()) {
+	let has_playground = code_blocks
+		.iter()
+		.flat_map(|b| &b.components)
+		.any(|c| c.playground.is_some());
+	let has_tabs = code_blocks
+		.iter()
+		.filter_map(|b| b.code_to_print.as_ref())
+		.any(|c| !c.resources.is_empty());
+
+	if has_playground || has_tabs {
 		new_content.push_str(&format!(
 			r#"<script type="module" src="{ptr}/playground-io.min.js"></script>"#,
 		));
@@ -408,3 +1021,146 @@ pub(crate) fn process_markdown(
 		code_blocks,
 	}))
 }
+
+/// A numbered example that can be referred to via `{{#angular-ref id}}`
+pub(crate) struct ExampleReference<'a> {
+	/// Path of the chapter containing the example
+	path: &'a Path,
+	label: &'a ExampleLabel,
+}
+
+/// Collect all examples with an id in the entire book
+pub(crate) fn collect_references(
+	chapters: &[ChapterWithCodeBlocks],
+) -> Result<HashMap<&str, ExampleReference<'_>>> {
+	let mut references = HashMap::new();
+
+	for chapter in chapters {
+		for code_block in &chapter.code_blocks {
+			let (Some(id), Some(label)) = (code_block.attributes.get("id"), &code_block.label)
+			else {
+				continue;
+			};
+
+			let reference = ExampleReference {
+				path: &chapter.source_path,
+				label,
+			};
+
+			if let Some(existing) = references.insert(id.as_str(), reference) {
+				bail!(
+					"Duplicate example id {id} in {} and {}",
+					existing.path.display(),
+					chapter.source_path.display()
+				);
+			}
+		}
+	}
+
+	Ok(references)
+}
+
+/// Expand all `{{#angular-ref id}}` and `{{#angular-examples}}` tags in the
+/// given chapter
+///
+/// This has to run after all chapters have been processed, as references can
+/// point to examples in any chapter of the book.
+pub(crate) fn expand_references(
+	config: &Config,
+	chapter: &mut Chapter,
+	chapters: &[ChapterWithCodeBlocks],
+	references: &HashMap<&str, ExampleReference>,
+) -> Result<()> {
+	let Some(source_path) = &chapter.source_path else {
+		return Ok(());
+	};
+
+	let markdown = &chapter.content;
+	if !TAG_ANGULAR_REFERENCE.is_match(markdown) {
+		return Ok(());
+	}
+
+	let escaped_tags = fs::read_to_string(config.book_source_folder.join(source_path))
+		.ok()
+		.map(|source| find_escaped_tags(&source, markdown, &TAG_ANGULAR_REFERENCE))
+		.unwrap_or_default();
+	let code_ranges = find_code_ranges(markdown);
+
+	let mut result = String::with_capacity(markdown.len());
+	let mut last = 0;
+
+	for captures in TAG_ANGULAR_REFERENCE.captures_iter(markdown) {
+		let tag = captures.get(0).unwrap();
+
+		if code_ranges.iter().any(|range| range.contains(&tag.start())) {
+			continue;
+		}
+
+		if escaped_tags.contains(&tag.start()) || markdown[..tag.start()].ends_with('\\') {
+			continue;
+		}
+
+		let replacement = match captures.name("id") {
+			Some(id) => {
+				let Some(reference) = references.get(id.as_str()) else {
+					bail!(
+						"Unknown example id {} in {}",
+						id.as_str(),
+						source_path.display()
+					);
+				};
+
+				let link = if reference.path == source_path {
+					String::new()
+				} else {
+					format!(
+						"{}/{}",
+						path_to_root(source_path),
+						reference.path.to_string_lossy().replace('\\', "/")
+					)
+				};
+
+				format!(
+					"[{} {}]({link}#{})",
+					config.strings["example"], reference.label.number, reference.label.anchor
+				)
+			}
+			None => list_examples(config, chapters, source_path),
+		};
+
+		result.push_str(&markdown[last..tag.start()]);
+		result.push_str(&replacement);
+
+		last = tag.end();
+	}
+
+	result.push_str(&markdown[last..]);
+
+	chapter.content = result;
+
+	Ok(())
+}
+
+/// Render a list linking to all numbered examples of the given chapter
+fn list_examples(
+	config: &Config,
+	chapters: &[ChapterWithCodeBlocks],
+	source_path: &Path,
+) -> String {
+	let example = &config.strings["example"];
+
+	chapters
+		.iter()
+		.filter(|chapter| chapter.source_path == source_path)
+		.flat_map(|chapter| &chapter.code_blocks)
+		.filter_map(|code_block| code_block.label.as_ref())
+		.map(|label| match &label.caption {
+			Some(caption) => format!(
+				"- [{example} {}: {caption}](#{})",
+				label.number, label.anchor
+			),
+			None => format!("- [{example} {}](#{})", label.number, label.anchor),
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
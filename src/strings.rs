@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+
+/// Strings shown by the default template, also used as fallback for strings
+/// missing in the other languages
+const ENGLISH: &[(&str, &str)] = &[
+	("show_code", "Show code"),
+	("inputs", "Inputs"),
+	("actions", "Actions"),
+	("name", "Name"),
+	("description", "Description"),
+	("value", "Value"),
+	("action", "Action"),
+	("example", "Example"),
+	("outputs", "Outputs"),
+	("output", "Output"),
+	("events", "Events"),
+	("clear", "Clear"),
+];
+
+const DUTCH: &[(&str, &str)] = &[
+	("show_code", "Toon code"),
+	("inputs", "Invoer"),
+	("actions", "Acties"),
+	("name", "Naam"),
+	("description", "Beschrijving"),
+	("value", "Waarde"),
+	("action", "Actie"),
+	("example", "Voorbeeld"),
+	("outputs", "Uitvoer"),
+	("output", "Uitvoer"),
+	("events", "Gebeurtenissen"),
+	("clear", "Wissen"),
+];
+
+const GERMAN: &[(&str, &str)] = &[
+	("show_code", "Code anzeigen"),
+	("inputs", "Eingaben"),
+	("actions", "Aktionen"),
+	("name", "Name"),
+	("description", "Beschreibung"),
+	("value", "Wert"),
+	("action", "Aktion"),
+	("example", "Beispiel"),
+	("outputs", "Ausgaben"),
+	("output", "Ausgabe"),
+	("events", "Ereignisse"),
+	("clear", "Leeren"),
+];
+
+const FRENCH: &[(&str, &str)] = &[
+	("show_code", "Afficher le code"),
+	("inputs", "Entrées"),
+	("actions", "Actions"),
+	("name", "Nom"),
+	("description", "Description"),
+	("value", "Valeur"),
+	("action", "Action"),
+	("example", "Exemple"),
+	("outputs", "Sorties"),
+	("output", "Sortie"),
+	("events", "Événements"),
+	("clear", "Effacer"),
+];
+
+const SPANISH: &[(&str, &str)] = &[
+	("show_code", "Mostrar código"),
+	("inputs", "Entradas"),
+	("actions", "Acciones"),
+	("name", "Nombre"),
+	("description", "Descripción"),
+	("value", "Valor"),
+	("action", "Acción"),
+	("example", "Ejemplo"),
+	("outputs", "Salidas"),
+	("output", "Salida"),
+	("events", "Eventos"),
+	("clear", "Borrar"),
+];
+
+/// Get the built-in strings for the given language, e.g. `nl` or `de-AT`
+fn get_builtin_strings(language: &str) -> Option<&'static [(&'static str, &'static str)]> {
+	let language = language
+		.split(['-', '_'])
+		.next()
+		.unwrap_or_default()
+		.to_lowercase();
+
+	match language.as_str() {
+		"en" => Some(ENGLISH),
+		"nl" => Some(DUTCH),
+		"de" => Some(GERMAN),
+		"fr" => Some(FRENCH),
+		"es" => Some(SPANISH),
+		_ => None,
+	}
+}
+
+/// Get the strings to pass to the template for a book in the given language
+///
+/// Strings missing from the built-in translations fall back to English, and
+/// the given overrides take precedence over any built-in string.
+pub(crate) fn get_strings(
+	language: Option<&str>,
+	overrides: BTreeMap<String, String>,
+) -> BTreeMap<String, String> {
+	let builtin = language.and_then(get_builtin_strings).unwrap_or_default();
+
+	let mut strings: BTreeMap<String, String> = ENGLISH
+		.iter()
+		.chain(builtin)
+		.map(|&(key, value)| (key.to_owned(), value.to_owned()))
+		.collect();
+
+	strings.extend(overrides);
+
+	strings
+}
+
+#[cfg(test)]
+mod test {
+	use std::collections::BTreeMap;
+
+	use super::get_strings;
+
+	#[test]
+	fn test_get_strings() {
+		let strings = get_strings(
+			Some("nl-BE"),
+			BTreeMap::from([("inputs".to_owned(), "Invoervelden".to_owned())]),
+		);
+
+		assert_eq!("Toon code", strings["show_code"]);
+		assert_eq!("Invoervelden", strings["inputs"]);
+
+		assert_eq!(
+			"Show code",
+			get_strings(Some("tlh"), BTreeMap::new())["show_code"]
+		);
+		assert_eq!(
+			"Code anzeigen",
+			get_strings(Some("de_DE"), BTreeMap::new())["show_code"]
+		);
+	}
+}
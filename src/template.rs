@@ -0,0 +1,127 @@
+use std::fs;
+
+use handlebars::{handlebars_helper, Handlebars};
+
+use crate::{markdown::render_markdown, Config, Context, Result};
+
+/// Partials used by the built-in templates
+const PARTIALS: &[(&str, &str)] = &[
+	(
+		"mdbook-angular-caption",
+		include_str!("templates/caption.hbs"),
+	),
+	("mdbook-angular-code", include_str!("templates/code.hbs")),
+	(
+		"mdbook-angular-playground",
+		include_str!("templates/playground.hbs"),
+	),
+];
+
+/// Templates that can be selected via the `template=` attribute
+const TEMPLATES: &[(&str, &str)] = &[
+	("minimal", include_str!("templates/minimal.hbs")),
+	("card", include_str!("templates/card.hbs")),
+	("demo-only", include_str!("templates/demo-only.hbs")),
+];
+
+handlebars_helper!(json: |value: Json| serde_json::to_string(value).unwrap_or_default());
+
+// Accept any value, so missing descriptions render as nothing instead of failing
+handlebars_helper!(markdown: |text: Json| text.as_str().map(render_markdown).unwrap_or_default());
+
+/// Name of the template registered for the given `template=` attribute
+pub(crate) fn get_template_name(template: Option<&str>) -> String {
+	match template {
+		Some(template) => format!("playground-{template}"),
+		None => "playground".to_owned(),
+	}
+}
+
+/// Whether the given template shows the explanations of callouts below the
+/// code, which the built-in `minimal` and `demo-only` templates don't
+pub(crate) fn shows_callouts(template: Option<&str>) -> bool {
+	!matches!(template, Some("minimal" | "demo-only"))
+}
+
+/// Create the handlebars registry to render the examples of the entire book
+///
+/// This registers the `playground` template, which is the book's
+/// `theme/angular-playground.hbs` if it exists, the built-in templates and the
+/// book's `theme/angular-playground-<name>.hbs` templates, every partial in the
+/// book's `theme/angular-partials` folder, and the helpers available to
+/// templates.
+pub(crate) fn create_handlebars(config: &Config) -> Result<Handlebars<'static>> {
+	let mut handlebars = Handlebars::new();
+
+	// Don't escape anything
+	handlebars.register_escape_fn(std::borrow::ToOwned::to_owned);
+
+	// The `eq` helper is built into handlebars
+	handlebars.register_helper("json", Box::new(json));
+	handlebars.register_helper("markdown", Box::new(markdown));
+
+	for (name, partial) in PARTIALS {
+		handlebars.register_partial(name, partial)?;
+	}
+
+	let partials_folder = config.book_theme_folder.join("angular-partials");
+	if partials_folder.is_dir() {
+		let mut partials = fs::read_dir(&partials_folder)
+			.context("Failed to read angular partials")?
+			.map(|entry| Ok(entry?.path()))
+			.collect::<Result<Vec<_>>>()?;
+		partials.sort();
+
+		for path in partials {
+			if path.extension().is_none_or(|ext| ext != "hbs") {
+				continue;
+			}
+
+			let Some(name) = path.file_stem().and_then(|name| name.to_str()) else {
+				continue;
+			};
+
+			let partial = fs::read_to_string(&path)
+				.with_context(|| format!("Failed to read partial {}", path.display()))?;
+			handlebars
+				.register_partial(name, partial)
+				.with_context(|| format!("Invalid partial {}", path.display()))?;
+		}
+	}
+
+	let template_path = config.book_theme_folder.join("angular-playground.hbs");
+	if template_path.exists() {
+		handlebars.register_template_file("playground", template_path)?;
+	} else {
+		handlebars.register_template_string("playground", include_str!("templates/default.hbs"))?;
+	}
+
+	for (name, template) in TEMPLATES {
+		handlebars.register_template_string(&get_template_name(Some(name)), template)?;
+	}
+
+	if config.book_theme_folder.is_dir() {
+		let mut templates = fs::read_dir(&config.book_theme_folder)
+			.context("Failed to read theme folder")?
+			.map(|entry| Ok(entry?.path()))
+			.collect::<Result<Vec<_>>>()?;
+		templates.sort();
+
+		for path in templates {
+			let Some(name) = path
+				.file_name()
+				.and_then(|name| name.to_str())
+				.and_then(|name| name.strip_prefix("angular-playground-"))
+				.and_then(|name| name.strip_suffix(".hbs"))
+			else {
+				continue;
+			};
+
+			handlebars
+				.register_template_file(&get_template_name(Some(name)), &path)
+				.with_context(|| format!("Invalid template {}", path.display()))?;
+		}
+	}
+
+	Ok(handlebars)
+}
@@ -0,0 +1,58 @@
+/// Remove `//` and `/* */` comments from JSON, which tsconfig files allow
+pub(crate) fn strip_json_comments(json: &str) -> String {
+	let mut result = String::with_capacity(json.len());
+	let mut chars = json.chars().peekable();
+	let mut in_string = false;
+
+	while let Some(c) = chars.next() {
+		if in_string {
+			result.push(c);
+
+			match c {
+				'\\' => result.extend(chars.next()),
+				'"' => in_string = false,
+				_ => {}
+			}
+		} else if c == '/' && chars.peek() == Some(&'/') {
+			if chars.by_ref().any(|c| c == '\n') {
+				result.push('\n');
+			}
+		} else if c == '/' && chars.peek() == Some(&'*') {
+			chars.next();
+
+			let mut last = None;
+			for c in chars.by_ref() {
+				if last == Some('*') && c == '/' {
+					break;
+				}
+				last = Some(c);
+			}
+
+			result.push(' ');
+		} else {
+			in_string = c == '"';
+			result.push(c);
+		}
+	}
+
+	result
+}
+
+#[cfg(test)]
+mod test {
+	use super::strip_json_comments;
+
+	#[test]
+	fn test_strip_json_comments() {
+		assert_eq!(
+			"\n{\n\t\"url\": \"https://lorem/*ipsum*/\",   \"a\": 1 \n}",
+			strip_json_comments(
+				"// Comment\n{\n\t\"url\": \"https://lorem/*ipsum*/\", /* a */ \"a\": 1 // b\n}"
+			)
+		);
+		assert_eq!(
+			r#"{"escaped": "\"//\""}"#,
+			strip_json_comments(r#"{"escaped": "\"//\""}"#)
+		);
+	}
+}
@@ -1,4 +1,6 @@
+mod json;
 mod path;
 pub(crate) mod swc;
 
-pub(crate) use path::path_to_root;
+pub(crate) use json::strip_json_comments;
+pub(crate) use path::{normalize_path, path_to_root, to_js_path};
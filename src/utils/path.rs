@@ -1,4 +1,10 @@
-use std::path::Path;
+use std::{
+	borrow::Cow,
+	path::{Component, Path, PathBuf},
+};
+
+use once_cell::sync::Lazy;
+use regex::Regex;
 
 pub(crate) fn path_to_root<P: AsRef<Path>>(path: P) -> String {
 	let mut parts = Vec::new();
@@ -20,9 +26,45 @@ pub(crate) fn path_to_root<P: AsRef<Path>>(path: P) -> String {
 	}
 }
 
+/// Replace a TypeScript extension with the matching JavaScript extension
+///
+/// TypeScript expects imports to refer to the compiled JavaScript file.
+pub(crate) fn to_js_path(path: &str) -> Cow<'_, str> {
+	static TS_EXT: Lazy<Regex> = Lazy::new(|| Regex::new(r"\.([cm]?)ts(x?)$").unwrap());
+
+	TS_EXT.replace_all(path, ".${1}js${2}")
+}
+
+/// Resolve `.` and `..` components in the given path without accessing the
+/// file system
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
+	let mut result = PathBuf::new();
+
+	for component in path.components() {
+		match component {
+			Component::CurDir => {}
+			Component::ParentDir => {
+				if matches!(
+					result.components().next_back(),
+					None | Some(Component::ParentDir)
+				) {
+					result.push("..");
+				} else {
+					result.pop();
+				}
+			}
+			component => result.push(component),
+		}
+	}
+
+	result
+}
+
 #[cfg(test)]
 mod test {
-	use super::path_to_root;
+	use std::path::Path;
+
+	use super::{normalize_path, path_to_root, to_js_path};
 
 	#[test]
 	fn test_path_to_root() {
@@ -30,4 +72,26 @@ mod test {
 		assert_eq!("../..", path_to_root("lorem/ipsum/dolor.html"));
 		assert_eq!(".", path_to_root("lorem.html"));
 	}
+
+	#[test]
+	fn test_to_js_path() {
+		assert_eq!("lorem/ipsum.js", to_js_path("lorem/ipsum.ts"));
+		assert_eq!("lorem.mjs", to_js_path("lorem.mts"));
+		assert_eq!("lorem.cjsx", to_js_path("lorem.ctsx"));
+		assert_eq!("lorem.js", to_js_path("lorem.js"));
+		assert_eq!("lorem.ts.html", to_js_path("lorem.ts.html"));
+	}
+
+	#[test]
+	fn test_normalize_path() {
+		assert_eq!(
+			Path::new("/lorem/dolor"),
+			normalize_path(Path::new("/lorem/./ipsum/../dolor"))
+		);
+		assert_eq!(
+			Path::new("../lorem"),
+			normalize_path(Path::new("ipsum/../../lorem"))
+		);
+		assert_eq!(Path::new("/"), normalize_path(Path::new("/lorem/..")));
+	}
 }
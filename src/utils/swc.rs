@@ -58,3 +58,20 @@ pub(crate) fn get_decorator<'a>(
 			.map_or(false, |ident| ident.sym.as_ref() == name)
 	})
 }
+
+pub(crate) fn get_property<'a>(
+	object: &'a ast::ObjectLit,
+	name: &str,
+) -> Option<&'a ast::KeyValueProp> {
+	object
+		.props
+		.iter()
+		.filter_map(ast::PropOrSpread::as_prop)
+		.map(Box::as_ref)
+		.filter_map(ast::Prop::as_key_value)
+		.find(|kv| match &kv.key {
+			ast::PropName::Ident(ident) => ident.sym.eq(name),
+			ast::PropName::Str(str) => str.value.eq(name),
+			_ => false,
+		})
+}
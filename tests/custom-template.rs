@@ -0,0 +1,62 @@
+mod fixture;
+
+use fixture::Fixture;
+
+#[test]
+fn test_template_context() {
+	let fixture = Fixture::run_without_build_with_theme("custom-theme", None);
+	let chapter = fixture.chapter14();
+
+	chapter.assert_class_texts(
+		"example-header",
+		&[
+			"Chapter 14 #0: FirstComponent as <codeblock-0>",
+			"Chapter 14 #0: SecondComponent as <codeblock-0-1>",
+			"Chapter 14 #1: BeforeComponent as <before-component> from comparison.ts",
+			"Chapter 14 #1: AfterComponent as <after-component> from comparison.ts",
+		],
+	);
+	chapter.assert_class_attributes("example-details", "data-application", &["0", "1", "2", "3"]);
+	chapter.assert_class_attributes(
+		"example-details",
+		"data-chapter",
+		&[
+			"chapter-14.md",
+			"chapter-14.md",
+			"chapter-14.md",
+			"chapter-14.md",
+		],
+	);
+	chapter.assert_class_attributes(
+		"example-details",
+		"data-flags",
+		&[
+			r#"{"collapsed":false}"#,
+			r#"{"collapsed":false}"#,
+			r#"{"collapsed":false}"#,
+			r#"{"collapsed":false}"#,
+		],
+	);
+	chapter.assert_class_texts(
+		"example-details",
+		&["TypeScript", "TypeScript", "TypeScript", "TypeScript"],
+	);
+}
+
+#[test]
+fn test_template_helpers() {
+	let fixture = Fixture::run_without_build_with_theme("custom-theme", None);
+
+	fixture
+		.chapter20()
+		.assert_class_texts("example-input", &["The text to show, required\n"]);
+}
+
+#[test]
+fn test_named_templates() {
+	let fixture = Fixture::run_without_build_with_theme("custom-theme", None);
+	let chapter = fixture.chapter21();
+
+	chapter.assert_class_texts("custom-minimal", &["ExampleComponent"]);
+	chapter.assert_class_count("mdbook-angular-card", 1);
+}
@@ -1,3 +1,6 @@
+// Not every test file uses every helper in this shared module
+#![allow(dead_code)]
+
 use std::{
 	collections::HashMap,
 	fs,
@@ -29,7 +32,21 @@ impl Fixture {
 		Self::run(Some(map))
 	}
 
+	/// Build the fixture with the given folder inside `tests` as theme folder
+	pub fn run_without_build_with_theme(
+		theme: &str,
+		env: Option<HashMap<String, String>>,
+	) -> Fixture {
+		let mut map = env.unwrap_or_default();
+		map.insert("MDBOOK_ANGULAR_SKIP_BUILD".to_owned(), "1".to_owned());
+		Self::run_with_theme(Some(theme), Some(map))
+	}
+
 	pub fn run(env: Option<HashMap<String, String>>) -> Fixture {
+		Self::run_with_theme(None, env)
+	}
+
+	fn run_with_theme(theme: Option<&str>, env: Option<HashMap<String, String>>) -> Fixture {
 		let temp_dir = tempfile::Builder::new()
 			.prefix("mdbook-angular-tests")
 			.tempdir()
@@ -51,6 +68,16 @@ impl Fixture {
 		)
 		.expect("Failed to copy book.toml");
 
+		if let Some(theme) = theme {
+			copy_dir(
+				Path::new(env!("CARGO_MANIFEST_DIR"))
+					.join("tests")
+					.join(theme),
+				temp_dir.path().join("theme"),
+			)
+			.expect("Failed to copy theme");
+		}
+
 		let mut command = Command::new("yarn");
 		command
 			.arg("exec")
@@ -110,6 +137,150 @@ impl Fixture {
 		self.read_chapter("chapter-5.html")
 			.expect("couldn't read chapter-5.html")
 	}
+
+	pub fn chapter6(&self) -> Chapter {
+		self.read_chapter("chapter-6.html")
+			.expect("couldn't read chapter-6.html")
+	}
+
+	pub fn chapter7(&self) -> Chapter {
+		self.read_chapter("chapter-7.html")
+			.expect("couldn't read chapter-7.html")
+	}
+
+	pub fn chapter8(&self) -> Chapter {
+		self.read_chapter("chapter-8.html")
+			.expect("couldn't read chapter-8.html")
+	}
+
+	pub fn chapter9(&self) -> Chapter {
+		self.read_chapter("chapter-9.html")
+			.expect("couldn't read chapter-9.html")
+	}
+
+	pub fn chapter10(&self) -> Chapter {
+		self.read_chapter("chapter-10.html")
+			.expect("couldn't read chapter-10.html")
+	}
+
+	pub fn chapter11(&self) -> Chapter {
+		self.read_chapter("chapter-11.html")
+			.expect("couldn't read chapter-11.html")
+	}
+
+	pub fn chapter12(&self) -> Chapter {
+		self.read_chapter("chapter-12.html")
+			.expect("couldn't read chapter-12.html")
+	}
+
+	pub fn chapter13(&self) -> Chapter {
+		self.read_chapter("chapter-13.html")
+			.expect("couldn't read chapter-13.html")
+	}
+
+	pub fn chapter14(&self) -> Chapter {
+		self.read_chapter("chapter-14.html")
+			.expect("couldn't read chapter-14.html")
+	}
+
+	pub fn chapter15(&self) -> Chapter {
+		self.read_chapter("chapter-15.html")
+			.expect("couldn't read chapter-15.html")
+	}
+
+	pub fn chapter16(&self) -> Chapter {
+		self.read_chapter("chapter-16.html")
+			.expect("couldn't read chapter-16.html")
+	}
+
+	pub fn chapter17(&self) -> Chapter {
+		self.read_chapter("chapter-17.html")
+			.expect("couldn't read chapter-17.html")
+	}
+
+	pub fn chapter18(&self) -> Chapter {
+		self.read_chapter("chapter-18.html")
+			.expect("couldn't read chapter-18.html")
+	}
+
+	pub fn chapter19(&self) -> Chapter {
+		self.read_chapter("chapter-19.html")
+			.expect("couldn't read chapter-19.html")
+	}
+
+	pub fn chapter20(&self) -> Chapter {
+		self.read_chapter("chapter-20.html")
+			.expect("couldn't read chapter-20.html")
+	}
+
+	pub fn chapter21(&self) -> Chapter {
+		self.read_chapter("chapter-21.html")
+			.expect("couldn't read chapter-21.html")
+	}
+
+	pub fn chapter22(&self) -> Chapter {
+		self.read_chapter("chapter-22.html")
+			.expect("couldn't read chapter-22.html")
+	}
+
+	pub fn chapter23(&self) -> Chapter {
+		self.read_chapter("chapter-23.html")
+			.expect("couldn't read chapter-23.html")
+	}
+
+	pub fn chapter24(&self) -> Chapter {
+		self.read_chapter("chapter-24.html")
+			.expect("couldn't read chapter-24.html")
+	}
+
+	pub fn chapter25(&self) -> Chapter {
+		self.read_chapter("chapter-25.html")
+			.expect("couldn't read chapter-25.html")
+	}
+
+	pub fn chapter26(&self) -> Chapter {
+		self.read_chapter("chapter-26.html")
+			.expect("couldn't read chapter-26.html")
+	}
+
+	pub fn chapter27(&self) -> Chapter {
+		self.read_chapter("nested/chapter-27.html")
+			.expect("couldn't read nested/chapter-27.html")
+	}
+
+	/// Read the given file of the angular project written into the working
+	/// directory
+	pub fn read_working_file(&self, path: &str) -> String {
+		fs::read_to_string(self.0.path().join("mdbook_angular").join(path))
+			.unwrap_or_else(|_| panic!("couldn't read {path}"))
+	}
+
+	/// Read the code written to run the code block exporting the given class
+	pub fn code_to_run(&self, class_name: &str) -> String {
+		let export = format!("export class {class_name} ");
+
+		self.find_working_file(|code| code.contains(&export))
+			.unwrap_or_else(|| panic!("couldn't find code exporting {class_name}"))
+	}
+
+	/// Read the script bootstrapping the component with the given class
+	pub fn bootstrap_script(&self, class_name: &str) -> String {
+		let import = format!("{{{class_name} as CodeBlock_");
+
+		self.find_working_file(|code| code.contains(&import))
+			.unwrap_or_else(|| panic!("couldn't find script bootstrapping {class_name}"))
+	}
+
+	/// Find a file written into the folders of the chapters in the working
+	/// directory
+	fn find_working_file<P: Fn(&str) -> bool>(&self, predicate: P) -> Option<String> {
+		fs::read_dir(self.0.path().join("mdbook_angular"))
+			.expect("couldn't read working directory")
+			.filter_map(|project| fs::read_dir(project.ok()?.path()).ok())
+			.flatten()
+			.filter_map(|file| fs::read_to_string(file.ok()?.path()).ok())
+			.find(|code| predicate(code))
+	}
 }
 
 pub struct Chapter(Document);
@@ -150,6 +321,223 @@ impl Chapter {
 		);
 	}
 
+	pub fn assert_code(&self, code: &[&str]) {
+		assert_eq!(
+			code,
+			self.0
+				.find(Name("pre").child(Name("code")))
+				.map(|code| code.text().trim_end().to_owned())
+				.collect::<Vec<_>>()
+		);
+	}
+
+	pub fn assert_file_names(&self, names: &[&str]) {
+		assert_eq!(
+			names,
+			self.0
+				.find(Name("p").child(Name("code")))
+				.map(|code| code.text())
+				.collect::<Vec<_>>()
+		);
+	}
+
+	pub fn assert_hidden_line_count(&self, count: usize) {
+		assert_eq!(
+			count,
+			self.0
+				.find(Name("code").descendant(Class("boring")))
+				.count()
+		);
+	}
+
+	pub fn assert_paragraphs(&self, paragraphs: &[&str]) {
+		assert_eq!(
+			paragraphs,
+			self.0
+				.find(Name("main").child(Name("p")))
+				.map(|p| p.text())
+				.collect::<Vec<_>>()
+		);
+	}
+
+	pub fn assert_element_count(&self, name: &str, count: usize) {
+		assert_eq!(count, self.0.find(Name(name)).count());
+	}
+
+	pub fn assert_summaries(&self, summaries: &[&str]) {
+		assert_eq!(
+			summaries,
+			self.0
+				.find(Name("summary"))
+				.map(|summary| summary.text())
+				.collect::<Vec<_>>()
+		);
+	}
+
+	pub fn assert_inputs(&self, inputs: &[&str]) {
+		assert_eq!(
+			inputs,
+			self.0
+				.find(Name("mdbook-angular-input"))
+				.map(|input| input.inner_html())
+				.collect::<Vec<_>>()
+		);
+	}
+
+	pub fn assert_event_logs(&self, event_logs: &[&str]) {
+		assert_eq!(
+			event_logs,
+			self.0
+				.find(Name("mdbook-angular-events"))
+				.map(|event_log| event_log.inner_html())
+				.collect::<Vec<_>>()
+		);
+	}
+
+	pub fn assert_descriptions(&self, descriptions: &[&str]) {
+		assert_eq!(
+			descriptions,
+			self.0
+				.find(Class("mdbook-angular").descendant(Name("tr")))
+				.filter_map(|row| Some(row.find(Name("td")).nth(1)?.inner_html()))
+				.collect::<Vec<_>>()
+		);
+	}
+
+	pub fn assert_table_headers(&self, headers: &[&str]) {
+		assert_eq!(
+			headers,
+			self.0
+				.find(Class("mdbook-angular").descendant(Name("th")))
+				.map(|header| header.text())
+				.collect::<Vec<_>>()
+		);
+	}
+
+	pub fn assert_stylesheets(&self, stylesheets: &[&str]) {
+		assert_eq!(
+			stylesheets,
+			self.0
+				.find(Name("main").descendant(Name("link").and(Attr("rel", "stylesheet"))))
+				.filter_map(|link| link.attr("href"))
+				.collect::<Vec<_>>()
+		);
+	}
+
+	pub fn assert_class_count(&self, class: &str, count: usize) {
+		assert_eq!(count, self.0.find(Class(class)).count());
+	}
+
+	pub fn assert_class_texts(&self, class: &str, texts: &[&str]) {
+		assert_eq!(
+			texts,
+			self.0
+				.find(Class(class))
+				.map(|element| element.text())
+				.collect::<Vec<_>>()
+		);
+	}
+
+	pub fn assert_class_attributes(&self, class: &str, attribute: &str, values: &[&str]) {
+		assert_eq!(
+			values,
+			self.0
+				.find(Class(class))
+				.filter_map(|element| element.attr(attribute))
+				.collect::<Vec<_>>()
+		);
+	}
+
+	pub fn assert_input_indices(&self, indices: &[&str]) {
+		assert_eq!(
+			indices,
+			self.0
+				.find(Name("mdbook-angular-input"))
+				.filter_map(|input| input.attr("index"))
+				.collect::<Vec<_>>()
+		);
+	}
+
+	pub fn assert_input_models(&self, models: &[Option<&str>]) {
+		assert_eq!(
+			models,
+			self.0
+				.find(Name("mdbook-angular-input"))
+				.map(|input| input.attr("model"))
+				.collect::<Vec<_>>()
+		);
+	}
+
+	pub fn assert_tabs(&self, names: &[&str]) {
+		assert_eq!(
+			names,
+			self.0
+				.find(Name("mdbook-angular-tabs").descendant(Class("mdbook-angular-tab")))
+				.filter_map(|tab| tab.attr("data-name"))
+				.collect::<Vec<_>>()
+		);
+	}
+
+	pub fn assert_callouts(&self, callouts: &[&str]) {
+		assert_eq!(
+			callouts,
+			self.0
+				.find(Class("mdbook-angular-callouts").child(Name("li")))
+				.map(|callout| callout.inner_html())
+				.collect::<Vec<_>>()
+		);
+	}
+
+	pub fn assert_layouts(&self, layouts: &[&str]) {
+		assert_eq!(
+			layouts,
+			self.0
+				.find(Class("mdbook-angular-example"))
+				.filter_map(|example| {
+					example
+						.attr("class")?
+						.split(' ')
+						.find_map(|class| class.strip_prefix("mdbook-angular-layout-"))
+				})
+				.collect::<Vec<_>>()
+		);
+	}
+
+	pub fn assert_captions(&self, captions: &[&str]) {
+		assert_eq!(
+			captions,
+			self.0
+				.find(Class("mdbook-angular-caption"))
+				.map(|caption| caption.text())
+				.collect::<Vec<_>>()
+		);
+	}
+
+	pub fn assert_example_links(&self, links: &[(&str, &str)]) {
+		assert_eq!(
+			links
+				.iter()
+				.map(|&(text, href)| (text.to_owned(), href.to_owned()))
+				.collect::<Vec<_>>(),
+			self.0
+				.find(Name("main").descendant(Name("a")))
+				.filter(|link| link.text().starts_with("Example "))
+				.filter_map(|link| Some((link.text(), link.attr("href")?.to_owned())))
+				.collect::<Vec<_>>()
+		);
+	}
+
+	pub fn assert_has_id(&self, id: &str) {
+		assert_eq!(1, self.0.find(Attr("id", id)).count());
+	}
+
+	pub fn assert_nested_element_count(&self, parent: &str, name: &str, count: usize) {
+		assert_eq!(
+			count,
+			self.0.find(Name(parent).descendant(Name(name))).count()
+		);
+	}
+
 	pub fn assert_has_playground(&self, has_playground: bool) {
 		if !has_playground {
 			assert_eq!(0, self.0.find(Class("mdbook-angular-inputs")).count());
@@ -0,0 +1,21 @@
+import {Component, Input} from '@angular/core';
+
+@Component({
+	standalone: true,
+	selector: 'before-component',
+	template: `before: {{ text }}`,
+})
+export class BeforeComponent {
+	@Input()
+	text = 'before';
+}
+
+@Component({
+	standalone: true,
+	selector: 'after-component',
+	template: `after: {{ text }}`,
+})
+export class AfterComponent {
+	@Input()
+	text = 'after';
+}
@@ -7,6 +7,8 @@ import {ChangeDetectionStrategy, Component, Input} from '@angular/core';
 	changeDetection: ChangeDetectionStrategy.OnPush,
 })
 export class ExampleComponent {
+	// ANCHOR: input
 	@Input()
 	text = 'lorem ipsum';
+	// ANCHOR_END: input
 }
@@ -0,0 +1,11 @@
+import {Component} from '@angular/core';
+
+@Component({
+	standalone: true,
+	selector: 'greeting-component',
+	templateUrl: './greeting.component.html',
+	styleUrl: './greeting.component.css',
+})
+export class GreetingComponent {
+	name = 'world';
+}
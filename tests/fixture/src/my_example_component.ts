@@ -0,0 +1,9 @@
+import {ChangeDetectionStrategy, Component} from '@angular/core';
+
+@Component({
+	standalone: true,
+	selector: 'my-example',
+	template: `I'm an example with underscores in the file name`,
+	changeDetection: ChangeDetectionStrategy.OnPush,
+})
+export class MyExampleComponent {}
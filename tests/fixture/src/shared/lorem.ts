@@ -0,0 +1 @@
+export const LOREM = 'lorem ipsum';
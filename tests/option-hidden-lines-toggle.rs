@@ -0,0 +1,24 @@
+mod fixture;
+
+use std::collections::HashMap;
+
+use fixture::Fixture;
+
+fn options() -> Option<HashMap<String, String>> {
+	let mut map = HashMap::new();
+	map.insert(
+		"MDBOOK_OUTPUT__ANGULAR__HIDDEN_LINES_TOGGLE".to_owned(),
+		"true".to_owned(),
+	);
+	Some(map)
+}
+
+#[test]
+fn test_hidden_lines() {
+	let fixture = Fixture::run_without_build(options());
+	let chapter = fixture.chapter8();
+
+	chapter.assert_code_block_count(1);
+	chapter.assert_hidden_line_count(3);
+	chapter.assert_element_count("example-hidden", 1);
+}
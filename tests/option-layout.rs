@@ -0,0 +1,22 @@
+mod fixture;
+
+use std::collections::HashMap;
+
+use fixture::Fixture;
+
+fn options() -> Option<HashMap<String, String>> {
+	let mut map = HashMap::new();
+	map.insert(
+		"MDBOOK_OUTPUT__ANGULAR__LAYOUT".to_owned(),
+		"side-by-side".to_owned(),
+	);
+	Some(map)
+}
+
+#[test]
+fn test_layouts() {
+	let fixture = Fixture::run_without_build(options());
+	let chapter = fixture.chapter18();
+
+	chapter.assert_layouts(&["side-by-side", "preview-first", "side-by-side"]);
+}
@@ -0,0 +1,31 @@
+mod fixture;
+
+use std::collections::HashMap;
+
+use fixture::Fixture;
+
+fn options() -> Option<HashMap<String, String>> {
+	let mut map = HashMap::new();
+	map.insert("MDBOOK_BOOK__LANGUAGE".to_owned(), "de".to_owned());
+	map.insert(
+		"MDBOOK_OUTPUT__ANGULAR__STRINGS__VALUE".to_owned(),
+		"Startwert".to_owned(),
+	);
+	Some(map)
+}
+
+#[test]
+fn test_strings() {
+	let fixture = Fixture::run_without_build(options());
+
+	fixture
+		.chapter19()
+		.assert_captions(&["Beispiel 19.1: Example component", "Beispiel 19.2: After"]);
+	fixture.chapter20().assert_table_headers(&[
+		"Name",
+		"Beschreibung",
+		"Startwert",
+		"Aktion",
+		"Beschreibung",
+	]);
+}
@@ -0,0 +1,22 @@
+mod fixture;
+
+use std::collections::HashMap;
+
+use fixture::Fixture;
+
+fn options() -> Option<HashMap<String, String>> {
+	let mut map = HashMap::new();
+	map.insert(
+		"MDBOOK_OUTPUT__ANGULAR__THEME_STYLESHEET".to_owned(),
+		"false".to_owned(),
+	);
+	Some(map)
+}
+
+#[test]
+fn test_stylesheets() {
+	let fixture = Fixture::run_without_build(options());
+	let chapter = fixture.chapter1();
+
+	chapter.assert_stylesheets(&["./mdbook-angular.css"]);
+}
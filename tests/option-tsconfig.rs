@@ -0,0 +1,43 @@
+mod fixture;
+
+use std::collections::HashMap;
+
+use fixture::Fixture;
+
+fn options() -> Option<HashMap<String, String>> {
+	let mut map = HashMap::new();
+	map.insert(
+		"MDBOOK_OUTPUT__ANGULAR__TSCONFIG".to_owned(),
+		"src/tsconfig.json".to_owned(),
+	);
+	Some(map)
+}
+
+#[test]
+fn test_paths_and_aliases() {
+	let fixture = Fixture::run_without_build(options());
+
+	let tsconfig: serde_json::Value =
+		serde_json::from_str(&fixture.read_working_file("tsconfig.json")).unwrap();
+	assert!(
+		tsconfig["extends"]
+			.as_str()
+			.is_some_and(|path| path.ends_with("/src/tsconfig.json")),
+		"{tsconfig}"
+	);
+
+	let paths = &tsconfig["compilerOptions"]["paths"];
+	assert_eq!(2, paths.as_object().unwrap().len(), "{paths}");
+	assert!(
+		paths["@book/*"][0]
+			.as_str()
+			.is_some_and(|path| path.ends_with("/src/shared/*")),
+		"{paths}"
+	);
+	assert!(
+		paths["@lib/*"][0]
+			.as_str()
+			.is_some_and(|path| path.ends_with("/src/lib/*") && !path.contains("./")),
+		"{paths}"
+	);
+}
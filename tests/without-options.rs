@@ -56,3 +56,349 @@ fn test_hide() {
 	chapter.assert_code_block_count(0);
 	chapter.assert_has_playground(true);
 }
+
+#[test]
+fn test_file_group() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter6();
+
+	chapter.assert_code_block_count(3);
+	chapter.assert_file_names(&[
+		"example.service.ts",
+		"example.component.ts",
+		"hidden-entry.service.ts",
+	]);
+	chapter.assert_element_count("example-group", 1);
+	chapter.assert_element_count("hidden-entry-group", 1);
+}
+
+#[test]
+fn test_excerpts() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter7();
+
+	chapter.assert_code(&[
+		"@Input()\ntext = 'lorem ipsum';",
+		"import {ChangeDetectionStrategy, Component, Input} from '@angular/core';",
+	]);
+	chapter.assert_element_count("example-component", 1);
+}
+
+#[test]
+fn test_hidden_lines() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter8();
+
+	chapter.assert_code(&[
+		"@Component({\n\tstandalone: true,\n\tselector: 'example-hidden',\n\ttemplate: `lorem ipsum`,\n})\nexport class HiddenComponent {}",
+	]);
+	chapter.assert_hidden_line_count(0);
+	chapter.assert_element_count("example-hidden", 1);
+}
+
+#[test]
+fn test_escaped_tags() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter9();
+
+	chapter.assert_paragraphs(&[
+		"Escaped: {{#angular ./example.ts#ExampleComponent hide}} is shown as is.",
+		"Code span: {{#angular ./example.ts#ExampleComponent}}",
+	]);
+	chapter.assert_code(&["{{#angular ./example.ts#ExampleComponent}}"]);
+	// Only the tag in the included file is expanded
+	chapter.assert_element_count("example-component", 1);
+}
+
+#[test]
+fn test_tags_in_containers() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter10();
+
+	chapter.assert_code_block_count(2);
+	chapter.assert_element_count("my-example", 1);
+	chapter.assert_nested_element_count("li", "my-example", 1);
+	chapter.assert_nested_element_count("li", "pre", 1);
+	chapter.assert_nested_element_count("blockquote", "pre", 1);
+}
+
+#[test]
+fn test_unrelated_markdown() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter11();
+
+	chapter.assert_has_id("custom-id");
+	chapter.assert_has_id("note");
+	chapter.assert_element_count("table", 1);
+	chapter.assert_element_count("example-kept", 1);
+}
+
+#[test]
+fn test_attributes() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter12();
+
+	chapter.assert_collapsed(true);
+	chapter.assert_summaries(&["Show the second component", "Show the example"]);
+	chapter.assert_has_id("second-example");
+	chapter.assert_element_count("first-component", 0);
+	chapter.assert_element_count("second-component", 1);
+}
+
+#[test]
+fn test_input_bindings() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter13();
+
+	chapter.assert_inputs(&[r#"{"type":"string","default":"dolor sit"}"#]);
+}
+
+#[test]
+fn test_multiple_components() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter14();
+
+	chapter.assert_code_block_count(2);
+	chapter.assert_has_id("inline-comparison");
+	chapter.assert_element_count("codeblock-0", 1);
+	chapter.assert_element_count("codeblock-0-1", 1);
+	chapter.assert_element_count("before-component", 1);
+	chapter.assert_element_count("after-component", 1);
+	chapter.assert_input_indices(&["0", "1", "2", "3"]);
+}
+
+#[test]
+fn test_resource_tabs() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter15();
+
+	chapter.assert_code_block_count(3);
+	chapter.assert_tabs(&[
+		"greeting.component.ts",
+		"greeting.component.html",
+		"greeting.component.css",
+	]);
+	chapter.assert_element_count("greeting-component", 1);
+}
+
+#[test]
+fn test_split_template() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter16();
+
+	chapter.assert_tabs(&[
+		"component",
+		"template",
+		"styles",
+		"example.ts",
+		"template",
+		"component",
+		"template",
+	]);
+	chapter.assert_code(&[
+		"import {Component} from '@angular/core';\n\n@Component({\n\tstandalone: true,\n\tselector: 'split-template',\n\ttemplate: `...`,\n\tstyles: `...`,\n})\nexport class SplitTemplateComponent {}",
+		"<p class=\"split\">\n\tSplit\n</p>",
+		".split {\n\tcolor: red;\n}",
+		"@Component({\n\tstandalone: true,\n\tselector: 'example-component',\n\ttemplate: `...`,\n\tchangeDetection: ChangeDetectionStrategy.OnPush,\n})\nexport class ExampleComponent {\n\t@Input()\n\ttext = 'lorem ipsum';\n}",
+		"I'm a good example",
+		"import {Component} from '@angular/core';\n\n@Component({\n\tstandalone: true,\n\tselector: 'split-hidden-template',\n\ttemplate: `...`,\n})\nexport class SplitHiddenTemplateComponent {}",
+		"<p>visible</p>",
+	]);
+	chapter.assert_element_count("split-template", 1);
+	chapter.assert_element_count("split-hidden-template", 1);
+}
+
+#[test]
+fn test_callouts() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter17();
+
+	chapter.assert_code(&[
+		"import {Component} from '@angular/core';\n\n@Component({\n\tstandalone: true,\n\tselector: 'callout-example', // \u{2460}\n\ttemplate: `Callouts`, // \u{2461}\n})\nexport class CalloutComponent {}",
+		"import {Component} from '@angular/core';\n\n@Component({\n\tstandalone: true,\n\tselector: 'compared-callout-example', // \u{2460}\n\ttemplate: `Compared`,\n})\nexport class ComparedCalloutComponent {}",
+	]);
+	chapter.assert_callouts(&[
+		"The selector",
+		"The <em>inline</em> <code>template</code>",
+		"",
+	]);
+	// Examples without code keep the explanations in the chapter
+	chapter.assert_paragraphs(&[
+		"Lorem ipsum",
+		"<1> The hidden selector",
+		"<1> The demo selector",
+		"<1> Compare with",
+	]);
+	chapter.assert_element_count("hidden-callout-example", 1);
+	chapter.assert_element_count("demo-callout-example", 1);
+	// Lines containing the next example aren't taken as explanation
+	chapter.assert_element_count("compared-callout-example", 1);
+	chapter.assert_element_count("example-component", 1);
+}
+
+#[test]
+fn test_layouts() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter18();
+
+	chapter.assert_layouts(&["side-by-side", "preview-first", "stacked"]);
+}
+
+#[test]
+fn test_references() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter19();
+
+	chapter.assert_captions(&["Example 19.1: Example component", "Example 19.2: After"]);
+	chapter.assert_has_id("example-component");
+	chapter.assert_has_id("example-19-2");
+	chapter.assert_example_links(&[
+		("Example 19.1: Example component", "#example-component"),
+		("Example 19.2: After", "#example-19-2"),
+		("Example 19.1", "#example-component"),
+	]);
+
+	fixture
+		.chapter18()
+		.assert_example_links(&[("Example 19.1", "./chapter-19.html#example-component")]);
+}
+
+#[test]
+fn test_markdown_descriptions() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter20();
+
+	chapter.assert_descriptions(&[
+		"The <code>text</code> to show, <strong>required</strong>",
+		r#"Reset the text, see <a href="https://angular.dev">the docs</a>"#,
+	]);
+}
+
+#[test]
+fn test_stylesheets() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter1();
+
+	chapter.assert_stylesheets(&["./mdbook-angular.css", "./mdbook-angular-theme.css"]);
+}
+
+#[test]
+fn test_builtin_templates() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter21();
+
+	chapter.assert_code_block_count(2);
+	chapter.assert_class_count("mdbook-angular-minimal", 1);
+	chapter.assert_class_count("mdbook-angular-card", 1);
+	chapter.assert_class_count("mdbook-angular-preview", 3);
+	chapter.assert_captions(&["Example 21.1: Card"]);
+	chapter.assert_element_count("example-component", 1);
+	chapter.assert_element_count("before-component", 1);
+	chapter.assert_element_count("after-component", 1);
+}
+
+#[test]
+fn test_json_inputs() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter22();
+
+	chapter.assert_inputs(&[
+		r#"{"type":"json","default":["lorem","ipsum"]}"#,
+		r#"{"type":"json","default":[]}"#,
+		r#"{"type":"json","default":{"label":"dolor","nested":{"enabled":true},"size":2.0}}"#,
+		r#"{"type":"json","default":null}"#,
+		r#"{"type":"json","default":["x"]}"#,
+	]);
+}
+
+#[test]
+fn test_number_ranges() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter23();
+
+	chapter.assert_inputs(&[
+		r#"{"type":"number","default":5.0,"min":1.0,"max":10.0}"#,
+		r#"{"type":"number","default":0.5,"min":0.0,"max":1.0,"step":0.1}"#,
+		r#"{"type":"number","default":2.0,"min":0.0}"#,
+	]);
+}
+
+#[test]
+fn test_input_formats() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter24();
+
+	chapter.assert_inputs(&[
+		r##"{"type":"string","default":"#336699","format":"color"}"##,
+		r#"{"type":"string","default":null,"format":"multiline"}"#,
+		r#"{"type":"date","default":"2024-01-31"}"#,
+		r#"{"type":"date","default":"2024-02-01"}"#,
+		r#"{"type":"date","default":null,"format":"datetime"}"#,
+	]);
+
+	let script = fixture.bootstrap_script("FormatComponent");
+	assert!(
+		script.contains(r#"component.setInput("start", new Date("2024-02-01"));"#),
+		"{script}"
+	);
+}
+
+#[test]
+fn test_outputs() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter25();
+
+	chapter.assert_table_headers(&["Output", "Description"]);
+	chapter.assert_descriptions(&[
+		"Emits the number of clicks",
+		"",
+		"Emits the <em>selected</em> value",
+		"",
+		"",
+	]);
+	chapter.assert_event_logs(&[concat!(
+		r#"[{"name":"clicked","property":"clicked"},"#,
+		r#"{"name":"renamed","property":"original"},"#,
+		r#"{"name":"selected","property":"selected"},"#,
+		r#"{"name":"close","property":"closed"},"#,
+		r#"{"name":"ticks","property":"ticks"}]"#,
+	)]);
+}
+
+#[test]
+fn test_models() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter26();
+
+	chapter.assert_inputs(&[
+		r#"{"type":"number","default":0.0}"#,
+		r#"{"type":"boolean","default":null}"#,
+		r#"{"type":"string","default":""}"#,
+		r#"{"type":"string","default":"lorem"}"#,
+	]);
+	chapter.assert_input_models(&[Some("count"), Some("open"), Some("value"), None]);
+	chapter.assert_table_headers(&["Name", "Description", "Value"]);
+}
+
+#[test]
+fn test_relative_imports_and_aliases() {
+	let fixture = Fixture::run_without_build(None);
+	let chapter = fixture.chapter27();
+
+	chapter.assert_element_count("nested-example", 1);
+
+	let code = fixture.code_to_run("NestedComponent");
+	assert!(code.contains("from '../../src/example'"), "{code}");
+	assert!(code.contains("from '@book/lorem'"), "{code}");
+
+	let tsconfig: serde_json::Value =
+		serde_json::from_str(&fixture.read_working_file("tsconfig.json")).unwrap();
+	let paths = &tsconfig["compilerOptions"]["paths"];
+	assert_eq!(1, paths.as_object().unwrap().len(), "{paths}");
+	assert!(
+		paths["@book/*"][0]
+			.as_str()
+			.is_some_and(|path| path.ends_with("/src/shared/*")),
+		"{paths}"
+	);
+}